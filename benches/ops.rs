@@ -0,0 +1,96 @@
+//! Compare arithmetic on the numeric types against the equivalent raw f64
+//! arithmetic, to confirm the range invariants add no meaningful overhead.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use number::{BipolarFloat, Phase, UnipolarFloat};
+
+const N: usize = 4096;
+
+fn inputs() -> Vec<f64> {
+    // Deterministic spread of values, some of which lie out of range.
+    (0..N).map(|i| (i as f64 * 0.618_034) % 3.0 - 1.5).collect()
+}
+
+fn unipolar(c: &mut Criterion) {
+    let raw = inputs();
+    let unit: Vec<UnipolarFloat> = raw.iter().copied().map(UnipolarFloat::new).collect();
+    let master = UnipolarFloat::new(0.7);
+
+    let mut group = c.benchmark_group("unipolar");
+    group.bench_function("f64 clamp", |b| {
+        b.iter(|| raw.iter().map(|v| v.clamp(0.0, 1.0)).sum::<f64>())
+    });
+    group.bench_function("new", |b| {
+        b.iter(|| {
+            raw.iter()
+                .map(|v| UnipolarFloat::new(black_box(*v)).val())
+                .sum::<f64>()
+        })
+    });
+    group.bench_function("f64 mul", |b| {
+        b.iter(|| raw.iter().map(|v| v * black_box(0.7)).sum::<f64>())
+    });
+    group.bench_function("mul", |b| {
+        b.iter(|| {
+            unit.iter()
+                .map(|v| (*v * black_box(master)).val())
+                .sum::<f64>()
+        })
+    });
+    group.bench_function("add", |b| {
+        b.iter(|| {
+            unit.iter()
+                .map(|v| (*v + black_box(master)).val())
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+fn bipolar(c: &mut Criterion) {
+    let raw = inputs();
+    let bi: Vec<BipolarFloat> = raw.iter().copied().map(BipolarFloat::new).collect();
+    let offset = BipolarFloat::new(0.3);
+
+    let mut group = c.benchmark_group("bipolar");
+    group.bench_function("f64 add clamp", |b| {
+        b.iter(|| {
+            raw.iter()
+                .map(|v| (v + black_box(0.3)).clamp(-1.0, 1.0))
+                .sum::<f64>()
+        })
+    });
+    group.bench_function("add", |b| {
+        b.iter(|| {
+            bi.iter()
+                .map(|v| (*v + black_box(offset)).val())
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+fn phase(c: &mut Criterion) {
+    let raw = inputs();
+    let phases: Vec<Phase> = raw.iter().copied().map(Phase::new).collect();
+
+    let mut group = c.benchmark_group("phase");
+    group.bench_function("f64 rem_euclid", |b| {
+        b.iter(|| {
+            raw.iter()
+                .map(|v| (v + black_box(0.3)).rem_euclid(1.0))
+                .sum::<f64>()
+        })
+    });
+    group.bench_function("add", |b| {
+        b.iter(|| {
+            phases
+                .iter()
+                .map(|v| (*v + black_box(0.3)).val())
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, unipolar, bipolar, phase);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+use std::{
+    f64::consts::{PI, TAU},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Phase;
+
+/// An angle measured in radians.
+/// The angle is unbounded; use wrapped to constrain it to the range (-π, π].
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Angle(f64);
+
+impl Angle {
+    pub const ZERO: Self = Self(0.0);
+
+    /// Create an angle from a value in radians.
+    pub fn from_radians(v: f64) -> Self {
+        Self(v)
+    }
+
+    /// Create an angle from a value in degrees.
+    pub fn from_degrees(v: f64) -> Self {
+        Self(v.to_radians())
+    }
+
+    /// Return the angle in radians.
+    pub fn radians(&self) -> f64 {
+        self.0
+    }
+
+    /// Return the angle in degrees.
+    pub fn degrees(&self) -> f64 {
+        self.0.to_degrees()
+    }
+
+    /// Return this angle wrapped into the range (-π, π].
+    pub fn wrapped(&self) -> Self {
+        let v = self.0.rem_euclid(TAU);
+        if v > PI {
+            Self(v - TAU)
+        } else {
+            Self(v)
+        }
+    }
+
+    /// Return this angle as a Phase, wrapping it to a single rotation.
+    pub fn as_phase(&self) -> Phase {
+        Phase::new(self.0 / TAU)
+    }
+}
+
+impl From<Phase> for Angle {
+    fn from(value: Phase) -> Self {
+        Self(value.val() * TAU)
+    }
+}
+
+impl From<Angle> for Phase {
+    fn from(value: Angle) -> Self {
+        value.as_phase()
+    }
+}
+
+impl From<Angle> for f64 {
+    fn from(value: Angle) -> Self {
+        value.0
+    }
+}
+
+impl Add for Angle {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Angle {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Mul<f64> for Angle {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
@@ -0,0 +1,22 @@
+//! Apply arbitrary float functions while upholding the range invariant.
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+macro_rules! impl_apply {
+    ($t:ident, $restore:literal) => {
+        impl $t {
+            #[doc = concat!("Apply f to the inner value, then ", $restore, " the result.")]
+            pub fn map<F: Fn(f64) -> f64>(self, f: F) -> Self {
+                Self::new(f(self.0))
+            }
+
+            #[doc = concat!("Apply f to the inner values of self and other, then ", $restore, " the result.")]
+            pub fn combine<F: Fn(f64, f64) -> f64>(self, other: Self, f: F) -> Self {
+                Self::new(f(self.0, other.0))
+            }
+        }
+    };
+}
+
+impl_apply!(UnipolarFloat, "clamp");
+impl_apply!(BipolarFloat, "clamp");
+impl_apply!(Phase, "wrap");
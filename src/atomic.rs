@@ -0,0 +1,79 @@
+//! Lock-free shared control values.
+//!
+//! Values are stored as their f64 bit pattern in an AtomicU64. Only valid
+//! values can be stored, so the range invariant is upheld without locking.
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{BipolarFloat, UnipolarFloat};
+
+macro_rules! atomic_type {
+    ($(#[$doc:meta])* $name:ident, $t:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Default)]
+        pub struct $name(AtomicU64);
+
+        impl $name {
+            pub fn new(v: $t) -> Self {
+                Self(AtomicU64::new(v.0.to_bits()))
+            }
+
+            /// Load the value.
+            pub fn load(&self, order: Ordering) -> $t {
+                $t(f64::from_bits(self.0.load(order)))
+            }
+
+            /// Store a new value.
+            pub fn store(&self, v: $t, order: Ordering) {
+                self.0.store(v.0.to_bits(), order);
+            }
+
+            /// Store a new value, returning the previous value.
+            pub fn swap(&self, v: $t, order: Ordering) -> $t {
+                $t(f64::from_bits(self.0.swap(v.0.to_bits(), order)))
+            }
+
+            /// Atomically apply f to the current value, retrying if the value
+            /// was changed by another thread in the meantime.
+            /// See AtomicU64::fetch_update for the meaning of the orderings
+            /// and the result.
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$t, $t>
+            where
+                F: FnMut($t) -> Option<$t>,
+            {
+                self.0
+                    .fetch_update(set_order, fetch_order, |bits| {
+                        f($t(f64::from_bits(bits))).map(|v| v.0.to_bits())
+                    })
+                    .map(|bits| $t(f64::from_bits(bits)))
+                    .map_err(|bits| $t(f64::from_bits(bits)))
+            }
+
+            /// Consume the atomic and return the contained value.
+            pub fn into_inner(self) -> $t {
+                $t(f64::from_bits(self.0.into_inner()))
+            }
+        }
+
+        impl From<$t> for $name {
+            fn from(value: $t) -> Self {
+                Self::new(value)
+            }
+        }
+    };
+}
+
+atomic_type!(
+    /// A UnipolarFloat which can be shared between threads.
+    AtomicUnipolar,
+    UnipolarFloat
+);
+atomic_type!(
+    /// A BipolarFloat which can be shared between threads.
+    AtomicBipolar,
+    BipolarFloat
+);
@@ -0,0 +1,71 @@
+//! Compositing operations for layering levels, such as intensity masks.
+//!
+//! Each operation combines a base level with a blend level. The slice
+//! variants apply the operation in place to a base layer; levels are paired
+//! in order and excess entries in the longer slice are ignored.
+use crate::UnipolarFloat;
+
+/// Multiply the levels; the result is never brighter than either input.
+pub fn multiply(base: UnipolarFloat, blend: UnipolarFloat) -> UnipolarFloat {
+    base * blend
+}
+
+/// Invert, multiply, and invert; the result is never darker than either input.
+pub fn screen(base: UnipolarFloat, blend: UnipolarFloat) -> UnipolarFloat {
+    (base.invert() * blend.invert()).invert()
+}
+
+/// Multiply where the base is dark and screen where the base is bright,
+/// increasing contrast.
+pub fn overlay(base: UnipolarFloat, blend: UnipolarFloat) -> UnipolarFloat {
+    if base < 0.5 {
+        UnipolarFloat::new(2.0 * base.val() * blend.val())
+    } else {
+        UnipolarFloat::new(1.0 - 2.0 * base.invert().val() * blend.invert().val())
+    }
+}
+
+/// Add the levels, clamping at 1.
+pub fn add(base: UnipolarFloat, blend: UnipolarFloat) -> UnipolarFloat {
+    UnipolarFloat::clamped(base.0 + blend.0)
+}
+
+/// Subtract the blend level from the base, clamping at 0.
+pub fn subtract(base: UnipolarFloat, blend: UnipolarFloat) -> UnipolarFloat {
+    UnipolarFloat::clamped(base.0 - blend.0)
+}
+
+fn apply(
+    base: &mut [UnipolarFloat],
+    blend: &[UnipolarFloat],
+    op: fn(UnipolarFloat, UnipolarFloat) -> UnipolarFloat,
+) {
+    for (b, l) in base.iter_mut().zip(blend) {
+        *b = op(*b, *l);
+    }
+}
+
+/// Multiply blend into base in place.
+pub fn multiply_slice(base: &mut [UnipolarFloat], blend: &[UnipolarFloat]) {
+    apply(base, blend, multiply);
+}
+
+/// Screen blend into base in place.
+pub fn screen_slice(base: &mut [UnipolarFloat], blend: &[UnipolarFloat]) {
+    apply(base, blend, screen);
+}
+
+/// Overlay blend onto base in place.
+pub fn overlay_slice(base: &mut [UnipolarFloat], blend: &[UnipolarFloat]) {
+    apply(base, blend, overlay);
+}
+
+/// Add blend to base in place, clamping at 1.
+pub fn add_slice(base: &mut [UnipolarFloat], blend: &[UnipolarFloat]) {
+    apply(base, blend, add);
+}
+
+/// Subtract blend from base in place, clamping at 0.
+pub fn subtract_slice(base: &mut [UnipolarFloat], blend: &[UnipolarFloat]) {
+    apply(base, blend, subtract);
+}
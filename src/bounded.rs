@@ -0,0 +1,124 @@
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::{error::Error, fmt};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{clamp, UnipolarFloat};
+
+/// The bounds provided for a BoundedFloat were not a valid range.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InvalidBounds {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl fmt::Display for InvalidBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}] is not a valid range; bounds must be finite and min must be less than max",
+            self.min, self.max
+        )
+    }
+}
+
+impl Error for InvalidBounds {}
+
+/// A float constrained to a range only known at runtime, such as a range
+/// loaded from a fixture profile.
+/// The type upholds the range invariant by clamping the value to the range.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawBoundedFloat"))]
+pub struct BoundedFloat {
+    value: f64,
+    min: f64,
+    max: f64,
+}
+
+impl BoundedFloat {
+    /// Clamp the provided value into [min, max].
+    /// Return an error if the bounds are not finite or min is not less than
+    /// max.
+    pub fn new(v: f64, min: f64, max: f64) -> Result<Self, InvalidBounds> {
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(InvalidBounds { min, max });
+        }
+        let mut bf = Self {
+            value: min,
+            min,
+            max,
+        };
+        bf.set(v);
+        Ok(bf)
+    }
+
+    /// Map a unit value onto [min, max].
+    /// Return an error if the bounds are invalid.
+    pub fn from_unipolar(v: UnipolarFloat, min: f64, max: f64) -> Result<Self, InvalidBounds> {
+        let mut bf = Self::new(min, min, max)?;
+        bf.set_unipolar(v);
+        Ok(bf)
+    }
+
+    /// Return the inner float value.
+    pub fn val(&self) -> f64 {
+        self.value
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Set the value, clamping it into range.
+    pub fn set(&mut self, mut v: f64) {
+        clamp(&mut v, self.min, self.max);
+        self.value = v;
+    }
+
+    /// Set the value from a unit value mapped onto the range.
+    pub fn set_unipolar(&mut self, v: UnipolarFloat) {
+        self.set(self.min + (self.max - self.min) * v.val());
+    }
+
+    /// Return the position of the value within the range.
+    /// min -> 0.0, max -> 1.0
+    pub fn to_unipolar(&self) -> UnipolarFloat {
+        UnipolarFloat::new((self.value - self.min) / (self.max - self.min))
+    }
+}
+
+impl From<BoundedFloat> for f64 {
+    fn from(value: BoundedFloat) -> Self {
+        value.value
+    }
+}
+
+impl From<BoundedFloat> for UnipolarFloat {
+    fn from(value: BoundedFloat) -> Self {
+        value.to_unipolar()
+    }
+}
+
+/// The serialized form, validated when deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawBoundedFloat {
+    value: f64,
+    min: f64,
+    max: f64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawBoundedFloat> for BoundedFloat {
+    type Error = InvalidBounds;
+    fn try_from(raw: RawBoundedFloat) -> Result<Self, Self::Error> {
+        Self::new(raw.value, raw.min, raw.max)
+    }
+}
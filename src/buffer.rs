@@ -0,0 +1,116 @@
+use std::{iter::FromIterator, slice};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{htp_slice, UnipolarFloat};
+
+/// An owned buffer of levels, such as one frame of channel values, with
+/// bulk operations over the whole buffer.
+/// Operations combining two buffers pair levels in order and stop at the
+/// end of the shorter buffer.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnipolarBuffer(Vec<UnipolarFloat>);
+
+impl UnipolarBuffer {
+    /// Create a buffer of the provided length, filled with zeros.
+    pub fn new(len: usize) -> Self {
+        Self(vec![UnipolarFloat::ZERO; len])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[UnipolarFloat] {
+        &self.0
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [UnipolarFloat] {
+        &mut self.0
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, UnipolarFloat> {
+        self.0.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, UnipolarFloat> {
+        self.0.iter_mut()
+    }
+
+    /// Return the inner vector.
+    pub fn into_inner(self) -> Vec<UnipolarFloat> {
+        self.0
+    }
+
+    /// Set every level to the provided value.
+    pub fn fill(&mut self, level: UnipolarFloat) {
+        self.0.fill(level);
+    }
+
+    /// Scale every level by a master level.
+    pub fn scale(&mut self, master: UnipolarFloat) {
+        for v in &mut self.0 {
+            *v *= master;
+        }
+    }
+
+    /// Add the levels in other to this buffer, clamping at 1.
+    pub fn add_saturating(&mut self, other: &Self) {
+        for (v, o) in self.0.iter_mut().zip(&other.0) {
+            *v = UnipolarFloat::clamped(v.0 + o.0);
+        }
+    }
+
+    /// Replace each level with the greater of it and the level in other.
+    pub fn max_with(&mut self, other: &Self) {
+        htp_slice(&mut self.0, &other.0);
+    }
+}
+
+impl From<Vec<UnipolarFloat>> for UnipolarBuffer {
+    fn from(value: Vec<UnipolarFloat>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<UnipolarBuffer> for Vec<UnipolarFloat> {
+    fn from(value: UnipolarBuffer) -> Self {
+        value.0
+    }
+}
+
+impl FromIterator<UnipolarFloat> for UnipolarBuffer {
+    fn from_iter<I: IntoIterator<Item = UnipolarFloat>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for UnipolarBuffer {
+    type Item = UnipolarFloat;
+    type IntoIter = std::vec::IntoIter<UnipolarFloat>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a UnipolarBuffer {
+    type Item = &'a UnipolarFloat;
+    type IntoIter = slice::Iter<'a, UnipolarFloat>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut UnipolarBuffer {
+    type Item = &'a mut UnipolarFloat;
+    type IntoIter = slice::IterMut<'a, UnipolarFloat>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
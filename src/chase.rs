@@ -0,0 +1,233 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Phase, UnipolarFloat};
+
+/// Which way a Chase travels across its outputs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ChaseDirection {
+    /// Travel from the first output to the last, then wrap around.
+    #[default]
+    Forward,
+    /// Travel from the last output to the first, then wrap around.
+    Reverse,
+    /// Travel from the first output to the last and back again.
+    Bounce,
+}
+
+/// A band of light which travels across a row of outputs once per rotation
+/// of a driving phase.
+///
+/// Width and fade are measured in outputs. The band is width outputs wide,
+/// at least one, and each edge crossfades over fade outputs, at most one.
+/// With a width of one and a fade of one, adjacent outputs crossfade into
+/// each other, always summing to full.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chase {
+    outputs: usize,
+    width: f64,
+    fade: UnipolarFloat,
+    direction: ChaseDirection,
+}
+
+impl Chase {
+    /// Create a forward chase across the provided number of outputs,
+    /// lighting one at a time with hard edges.
+    pub fn new(outputs: usize) -> Self {
+        Self {
+            outputs,
+            width: 1.0,
+            fade: UnipolarFloat::ZERO,
+            direction: ChaseDirection::default(),
+        }
+    }
+
+    /// Set the width of the band, in outputs. Widths less than one are
+    /// treated as one.
+    pub fn with_width(mut self, width: f64) -> Self {
+        self.width = f64::max(width, 1.0);
+        self
+    }
+
+    /// Set the width of the crossfade at each edge of the band, in outputs.
+    pub fn with_fade(mut self, fade: UnipolarFloat) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: ChaseDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn outputs(&self) -> usize {
+        self.outputs
+    }
+
+    /// Return the location of the center of the band, in outputs.
+    fn head(&self, phase: Phase) -> f64 {
+        let n = self.outputs as f64;
+        match self.direction {
+            ChaseDirection::Forward => phase.canonical() * n,
+            ChaseDirection::Reverse => phase.mirror().canonical() * n,
+            ChaseDirection::Bounce => phase.fold().0 * (n - 1.0),
+        }
+    }
+
+    /// Return the level of a single output at the provided phase.
+    /// Outputs past the end of the chase are always off.
+    pub fn level(&self, phase: Phase, index: usize) -> UnipolarFloat {
+        if index >= self.outputs {
+            return UnipolarFloat::ZERO;
+        }
+        self.level_at(self.head(phase), index)
+    }
+
+    fn level_at(&self, head: f64, index: usize) -> UnipolarFloat {
+        let mut offset = index as f64 - head;
+        if self.direction != ChaseDirection::Bounce {
+            // The outputs are arranged in a circle.
+            let n = self.outputs as f64;
+            offset = (offset + n / 2.0).rem_euclid(n) - n / 2.0;
+        }
+        let distance = offset.abs();
+        let half_width = self.width / 2.0;
+        let fade = self.fade.0;
+        if fade == 0.0 {
+            // The band is half-open, so that a head exactly between two
+            // outputs lights one of them rather than neither.
+            return if -half_width <= offset && offset < half_width {
+                UnipolarFloat::ONE
+            } else {
+                UnipolarFloat::ZERO
+            };
+        }
+        UnipolarFloat::clamped((half_width + fade / 2.0 - distance) / fade)
+    }
+
+    /// Return the level of every output at the provided phase.
+    pub fn levels(&self, phase: Phase) -> Vec<UnipolarFloat> {
+        let mut levels = vec![UnipolarFloat::ZERO; self.outputs];
+        self.levels_into(phase, &mut levels);
+        levels
+    }
+
+    /// Write the level of every output at the provided phase into out.
+    /// Excess entries in out are set to zero.
+    pub fn levels_into(&self, phase: Phase, out: &mut [UnipolarFloat]) {
+        let head = self.head(phase);
+        for (i, level) in out.iter_mut().enumerate() {
+            *level = if i < self.outputs {
+                self.level_at(head, i)
+            } else {
+                UnipolarFloat::ZERO
+            };
+        }
+    }
+}
+
+/// Return the level of each pixel at the provided normalized positions,
+/// lit by a band of light centered on phase, such as for a wave travelling
+/// along an LED strip.
+/// The band is width wide and falls off linearly from its center; it wraps
+/// around from the end of the strip to the start.
+pub fn distribute(
+    phase: Phase,
+    positions: &[UnipolarFloat],
+    width: UnipolarFloat,
+) -> Vec<UnipolarFloat> {
+    let mut levels = vec![UnipolarFloat::ZERO; positions.len()];
+    distribute_into(phase, positions, width, &mut levels);
+    levels
+}
+
+/// Write the level of each pixel at the provided normalized positions into
+/// out, as for distribute.
+/// Only as many levels as there are positions are written.
+pub fn distribute_into(
+    phase: Phase,
+    positions: &[UnipolarFloat],
+    width: UnipolarFloat,
+    out: &mut [UnipolarFloat],
+) {
+    let half_width = width.0 / 2.0;
+    for (level, position) in out.iter_mut().zip(positions) {
+        *level = if half_width == 0.0 {
+            UnipolarFloat::ZERO
+        } else {
+            let distance = phase.distance_to(position.as_phase()).val().abs();
+            UnipolarFloat::clamped(1.0 - distance / half_width)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit(chase: &Chase, phase: f64) -> Vec<f64> {
+        chase
+            .levels(Phase::new(phase))
+            .iter()
+            .map(UnipolarFloat::val)
+            .collect()
+    }
+
+    #[test]
+    fn forward_chase_lights_one_output_at_a_time() {
+        let chase = Chase::new(4);
+        assert_eq!(lit(&chase, 0.0), [1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(lit(&chase, 0.25), [0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(lit(&chase, 0.75), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(lit(&chase, 1.0), [1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn reverse_chase_runs_from_the_last_output() {
+        let chase = Chase::new(4).with_direction(ChaseDirection::Reverse);
+        assert_eq!(lit(&chase, 0.0), [1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(lit(&chase, 0.25), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(lit(&chase, 0.5), [0.0, 0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn bounce_chase_turns_around_at_the_ends() {
+        let chase = Chase::new(3).with_direction(ChaseDirection::Bounce);
+        assert_eq!(lit(&chase, 0.0), [1.0, 0.0, 0.0]);
+        assert_eq!(lit(&chase, 0.25), [0.0, 1.0, 0.0]);
+        assert_eq!(lit(&chase, 0.5), [0.0, 0.0, 1.0]);
+        assert_eq!(lit(&chase, 0.75), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn head_between_outputs_lights_exactly_one() {
+        for direction in [ChaseDirection::Forward, ChaseDirection::Reverse] {
+            let chase = Chase::new(4).with_direction(direction);
+            for eighth in 0..8 {
+                let levels = lit(&chase, f64::from(eighth) / 8.0);
+                assert_eq!(levels.iter().sum::<f64>(), 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn adjacent_crossfades_sum_to_full() {
+        let chase = Chase::new(5).with_fade(UnipolarFloat::ONE);
+        for step in 0..100 {
+            let levels = lit(&chase, f64::from(step) / 100.0);
+            assert!((levels.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            assert!(levels.iter().filter(|l| **l > 0.0).count() <= 2);
+        }
+    }
+
+    #[test]
+    fn outputs_past_the_end_are_off() {
+        let chase = Chase::new(2);
+        assert_eq!(chase.level(Phase::ZERO, 2), UnipolarFloat::ZERO);
+        let mut out = [UnipolarFloat::ONE; 3];
+        chase.levels_into(Phase::ZERO, &mut out);
+        assert_eq!(out[2], UnipolarFloat::ZERO);
+    }
+}
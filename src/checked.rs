@@ -0,0 +1,64 @@
+//! Arithmetic which reports when a result would lie outside of the range.
+use crate::{BipolarFloat, UnipolarFloat};
+
+macro_rules! impl_checked {
+    ($t:ident) => {
+        impl $t {
+            /// Clamp the provided value into range.
+            /// Also return true if it was clamped; NaN counts as clamped.
+            pub fn new_checked_clamp(v: f64) -> (Self, bool) {
+                match Self::try_new(v) {
+                    Ok(v) => (v, false),
+                    Err(_) => (Self::new(v), true),
+                }
+            }
+
+            /// Add other to self, returning None if the result would be
+            /// out of range.
+            pub fn checked_add<T: Into<f64>>(self, rhs: T) -> Option<Self> {
+                Self::try_new(self.0 + rhs.into()).ok()
+            }
+
+            /// Subtract other from self, returning None if the result would
+            /// be out of range.
+            pub fn checked_sub<T: Into<f64>>(self, rhs: T) -> Option<Self> {
+                Self::try_new(self.0 - rhs.into()).ok()
+            }
+
+            /// Multiply self by other, returning None if the result would be
+            /// out of range.
+            pub fn checked_mul<T: Into<f64>>(self, rhs: T) -> Option<Self> {
+                Self::try_new(self.0 * rhs.into()).ok()
+            }
+
+            /// Add other to self, clamping the result into range.
+            /// Also return true if the result was clamped.
+            pub fn overflowing_add<T: Into<f64>>(self, rhs: T) -> (Self, bool) {
+                Self::new_checked_clamp(self.0 + rhs.into())
+            }
+
+            /// Subtract other from self, clamping the result into range.
+            /// Also return true if the result was clamped.
+            pub fn overflowing_sub<T: Into<f64>>(self, rhs: T) -> (Self, bool) {
+                Self::new_checked_clamp(self.0 - rhs.into())
+            }
+        }
+    };
+}
+
+impl_checked!(UnipolarFloat);
+impl_checked!(BipolarFloat);
+
+impl UnipolarFloat {
+    /// Subtract amount from self, saturating at zero.
+    /// Also return the part of amount which could not be subtracted, so that
+    /// it may be carried elsewhere. Amount should not be negative.
+    pub fn saturating_sub_rem<T: Into<f64>>(self, amount: T) -> (Self, f64) {
+        let v = self.0 - amount.into();
+        if v < 0.0 {
+            (Self::ZERO, -v)
+        } else {
+            (Self::new(v), 0.0)
+        }
+    }
+}
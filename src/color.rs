@@ -0,0 +1,145 @@
+use std::ops::Mul;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Phase, UnipolarFloat};
+
+/// An RGB color, with each channel represented as a UnipolarFloat.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Rgb {
+    pub r: UnipolarFloat,
+    pub g: UnipolarFloat,
+    pub b: UnipolarFloat,
+}
+
+impl Rgb {
+    pub const BLACK: Self = Self {
+        r: UnipolarFloat::ZERO,
+        g: UnipolarFloat::ZERO,
+        b: UnipolarFloat::ZERO,
+    };
+    pub const WHITE: Self = Self {
+        r: UnipolarFloat::ONE,
+        g: UnipolarFloat::ONE,
+        b: UnipolarFloat::ONE,
+    };
+
+    pub fn new(r: UnipolarFloat, g: UnipolarFloat, b: UnipolarFloat) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Scale every channel by a master level.
+    pub fn scale(&self, master: UnipolarFloat) -> Self {
+        Self {
+            r: self.r * master,
+            g: self.g * master,
+            b: self.b * master,
+        }
+    }
+
+    /// Linearly interpolate between this color and other.
+    /// t = 0 returns self, t = 1 returns other.
+    pub fn lerp(&self, other: &Self, t: UnipolarFloat) -> Self {
+        Self {
+            r: lerp(self.r, other.r, t),
+            g: lerp(self.g, other.g, t),
+            b: lerp(self.b, other.b, t),
+        }
+    }
+
+    /// Return this color as 8-bit channel values, rounding to nearest.
+    pub fn as_u8(&self) -> [u8; 3] {
+        [to_u8(self.r), to_u8(self.g), to_u8(self.b)]
+    }
+}
+
+impl Mul<UnipolarFloat> for Rgb {
+    type Output = Self;
+    fn mul(self, rhs: UnipolarFloat) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl From<Rgb> for [u8; 3] {
+    fn from(value: Rgb) -> Self {
+        value.as_u8()
+    }
+}
+
+fn lerp(a: UnipolarFloat, b: UnipolarFloat, t: UnipolarFloat) -> UnipolarFloat {
+    // A convex combination of two in-range values is always in range, but
+    // clamp anyway to guard against floating point error at the endpoints.
+    UnipolarFloat::clamped(a.val() + (b.val() - a.val()) * t.val())
+}
+
+fn to_u8(v: UnipolarFloat) -> u8 {
+    (v.val() * 255.0).round() as u8
+}
+
+/// A color hue, represented as a unit phase around the color wheel.
+/// 0.0 is red, 1/3 is green, 2/3 is blue.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hue(Phase);
+
+impl Hue {
+    /// Wrap the provided value into the unit range.
+    pub fn new(v: f64) -> Self {
+        Self(Phase::new(v))
+    }
+
+    /// Return the inner phase.
+    pub fn phase(&self) -> Phase {
+        self.0
+    }
+
+    /// Return the inner float value.
+    pub fn val(&self) -> f64 {
+        self.0.val()
+    }
+
+    /// Convert this hue to RGB at the provided saturation and value.
+    pub fn to_rgb(&self, sat: UnipolarFloat, val: UnipolarFloat) -> Rgb {
+        hsv_to_rgb(self.0, sat, val)
+    }
+}
+
+impl From<Phase> for Hue {
+    fn from(value: Phase) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Hue> for Phase {
+    fn from(value: Hue) -> Self {
+        value.0
+    }
+}
+
+/// Convert a color in HSV space into RGB.
+pub fn hsv_to_rgb(hue: Phase, sat: UnipolarFloat, val: UnipolarFloat) -> Rgb {
+    let (s, v) = (sat.val(), val.val());
+    if s == 0.0 {
+        return Rgb::new(val, val, val);
+    }
+    // Phase::ONE is a valid phase, wrap it back to red.
+    let h = (hue.val() * 6.0) % 6.0;
+    let sector = h.floor();
+    let frac = h - sector;
+
+    let p = UnipolarFloat::new(v * (1.0 - s));
+    let q = UnipolarFloat::new(v * (1.0 - s * frac));
+    let t = UnipolarFloat::new(v * (1.0 - s * (1.0 - frac)));
+
+    match sector as u8 {
+        0 => Rgb::new(val, t, p),
+        1 => Rgb::new(q, val, p),
+        2 => Rgb::new(p, val, t),
+        3 => Rgb::new(p, q, val),
+        4 => Rgb::new(t, p, val),
+        _ => Rgb::new(val, p, q),
+    }
+}
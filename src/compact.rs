@@ -0,0 +1,175 @@
+//! Compact integer serialization, for use with `#[serde(with = "...")]`.
+//!
+//! Values are quantized to integers spanning the range of the type when
+//! serialized with a binary format. Human-readable formats such as JSON
+//! keep the usual f64 representation.
+//!
+//! ```
+//! use number::{BipolarFloat, UnipolarFloat};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Channel {
+//!     #[serde(with = "number::compact::as_u8")]
+//!     level: UnipolarFloat,
+//!     #[serde(with = "number::compact::as_u8")]
+//!     pan: BipolarFloat,
+//! }
+//!
+//! let channel = Channel {
+//!     level: UnipolarFloat::ONE,
+//!     pan: BipolarFloat::ZERO,
+//! };
+//! let bytes = bincode::serialize(&channel).unwrap();
+//! assert_eq!(bytes.len(), 2);
+//! assert_eq!(bincode::deserialize::<Channel>(&bytes).unwrap(), channel);
+//! ```
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+/// Types which can be quantized to an integer spanning their range.
+pub trait Quantize: Sized {
+    /// Map this value onto [0.0, 1.0], from the bottom of the range to the top.
+    fn to_unit(&self) -> f64;
+
+    /// Map a value in [0.0, 1.0] back into the range of the type.
+    fn from_unit(v: f64) -> Self;
+
+    /// If true, the middle of the range must quantize exactly, so the top
+    /// integer value is left unused to span an even number of steps.
+    const EXACT_MIDPOINT: bool = false;
+}
+
+impl Quantize for UnipolarFloat {
+    fn to_unit(&self) -> f64 {
+        self.0
+    }
+
+    fn from_unit(v: f64) -> Self {
+        Self::new(v)
+    }
+}
+
+impl Quantize for BipolarFloat {
+    /// Zero must round-trip exactly, so that centered values stay centered.
+    const EXACT_MIDPOINT: bool = true;
+
+    fn to_unit(&self) -> f64 {
+        self.rescale_as_unipolar().0
+    }
+
+    fn from_unit(v: f64) -> Self {
+        UnipolarFloat::new(v).rescale_as_bipolar()
+    }
+}
+
+impl Quantize for Phase {
+    const EXACT_MIDPOINT: bool = true;
+
+    fn to_unit(&self) -> f64 {
+        self.0
+    }
+
+    fn from_unit(v: f64) -> Self {
+        // The top step is a full rotation; do not wrap it back to zero.
+        Self::clamped(v)
+    }
+}
+
+/// Return the number of steps spanning the range of T, for an integer type
+/// whose largest value is max.
+fn steps<T: Quantize>(max: f64) -> f64 {
+    if T::EXACT_MIDPOINT {
+        max - 1.0
+    } else {
+        max
+    }
+}
+
+macro_rules! compact_module {
+    ($name:ident, $int:ident, $serialize:ident) => {
+        #[doc = concat!("Serialize as a ", stringify!($int), " in binary formats.")]
+        pub mod $name {
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use super::{steps, Quantize};
+
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Quantize + Serialize,
+                S: Serializer,
+            {
+                if serializer.is_human_readable() {
+                    value.serialize(serializer)
+                } else {
+                    let steps = steps::<T>(f64::from($int::MAX));
+                    serializer.$serialize((value.to_unit() * steps).round() as $int)
+                }
+            }
+
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: Quantize + Deserialize<'de>,
+                D: Deserializer<'de>,
+            {
+                if deserializer.is_human_readable() {
+                    T::deserialize(deserializer)
+                } else {
+                    let v = $int::deserialize(deserializer)?;
+                    Ok(T::from_unit(
+                        f64::from(v) / steps::<T>(f64::from($int::MAX)),
+                    ))
+                }
+            }
+        }
+    };
+}
+
+compact_module!(as_u8, u8, serialize_u8);
+compact_module!(as_u16, u16, serialize_u16);
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Compact {
+        #[serde(with = "as_u8")]
+        unipolar: UnipolarFloat,
+        #[serde(with = "as_u8")]
+        bipolar: BipolarFloat,
+        #[serde(with = "as_u16")]
+        bipolar16: BipolarFloat,
+        #[serde(with = "as_u8")]
+        phase: Phase,
+    }
+
+    fn round_trip(v: &Compact) -> Compact {
+        bincode::deserialize(&bincode::serialize(v).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn exact_values_round_trip() {
+        for (u, b, p) in [(0.0, 0.0, 0.0), (1.0, 1.0, 0.5), (0.0, -1.0, 0.5)] {
+            let v = Compact {
+                unipolar: UnipolarFloat::new(u),
+                bipolar: BipolarFloat::new(b),
+                bipolar16: BipolarFloat::new(b),
+                phase: Phase::new(p),
+            };
+            assert_eq!(round_trip(&v), v);
+        }
+    }
+
+    #[test]
+    fn full_rotation_round_trips() {
+        let v = Compact {
+            unipolar: UnipolarFloat::ONE,
+            bipolar: BipolarFloat::ZERO,
+            bipolar16: BipolarFloat::ZERO,
+            phase: Phase::ONE,
+        };
+        assert_eq!(round_trip(&v).phase.val(), 1.0);
+    }
+}
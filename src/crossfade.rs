@@ -0,0 +1,81 @@
+use std::f64::consts::FRAC_PI_2;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BipolarFloat, UnipolarFloat};
+
+/// The shape of the gain curve applied to one side of a crossfader.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FadeCurve {
+    /// Gain is proportional to fader travel.
+    #[default]
+    Linear,
+    /// Gain follows a quarter sine, so the summed power of two sides at the
+    /// same position is constant.
+    EqualPower,
+    /// Gain is fully on until the fader reaches the far end of its travel.
+    Cut,
+}
+
+impl FadeCurve {
+    /// Return the gain of a side whose fader is open by the provided amount.
+    pub fn apply(&self, open: UnipolarFloat) -> UnipolarFloat {
+        match self {
+            Self::Linear => open,
+            Self::EqualPower => UnipolarFloat::new((open.val() * FRAC_PI_2).sin()),
+            Self::Cut => {
+                if open > 0.0 {
+                    UnipolarFloat::ONE
+                } else {
+                    UnipolarFloat::ZERO
+                }
+            }
+        }
+    }
+}
+
+/// Crossfade between two banks of levels, A and B.
+/// A position of 0 selects only bank A, and 1 selects only bank B.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Crossfader {
+    pub position: UnipolarFloat,
+    pub curve_a: FadeCurve,
+    pub curve_b: FadeCurve,
+}
+
+impl Crossfader {
+    pub fn new(curve_a: FadeCurve, curve_b: FadeCurve) -> Self {
+        Self {
+            position: UnipolarFloat::ZERO,
+            curve_a,
+            curve_b,
+        }
+    }
+
+    /// Set the position from a bipolar control.
+    /// -1 selects only bank A, and 1 selects only bank B.
+    pub fn set_position_bipolar(&mut self, position: BipolarFloat) {
+        self.position = position.rescale_as_unipolar();
+    }
+
+    /// Return the gains applied to banks A and B at the current position.
+    pub fn gains(&self) -> (UnipolarFloat, UnipolarFloat) {
+        (
+            self.curve_a.apply(self.position.invert()),
+            self.curve_b.apply(self.position),
+        )
+    }
+
+    /// Mix banks a and b into out, summing the scaled levels and clamping.
+    /// Levels are paired in order; out is only written as far as the
+    /// shortest of the three slices.
+    pub fn mix(&self, a: &[UnipolarFloat], b: &[UnipolarFloat], out: &mut [UnipolarFloat]) {
+        let (gain_a, gain_b) = self.gains();
+        for ((o, a), b) in out.iter_mut().zip(a).zip(b) {
+            *o = UnipolarFloat::clamped(a.0 * gain_a.0 + b.0 * gain_b.0);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! defmt formatting, for efficient logging from embedded targets.
+use defmt::{write, Format, Formatter};
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+impl Format for UnipolarFloat {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=f64}", self.0)
+    }
+}
+
+impl Format for BipolarFloat {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=f64}", self.0)
+    }
+}
+
+impl Format for Phase {
+    fn format(&self, f: Formatter) {
+        write!(f, "{=f64}", self.0)
+    }
+}
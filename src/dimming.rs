@@ -0,0 +1,48 @@
+//! Dimming curve presets, mapping a fader level onto an output level.
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::UnipolarFloat;
+
+/// Output is proportional to the fader level.
+pub fn linear(level: UnipolarFloat) -> UnipolarFloat {
+    level
+}
+
+/// Output is the square of the fader level, giving finer control at the
+/// bottom of the fader where the eye is most sensitive.
+pub fn square_law(level: UnipolarFloat) -> UnipolarFloat {
+    level * level
+}
+
+/// Output eases in at the bottom of the fader and out at the top.
+pub fn s_curve(level: UnipolarFloat) -> UnipolarFloat {
+    let t = level.val();
+    UnipolarFloat::new(t * t * (3.0 - 2.0 * t))
+}
+
+/// A selectable dimming curve preset.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DimmingCurve {
+    #[default]
+    Linear,
+    SquareLaw,
+    SCurve,
+}
+
+impl DimmingCurve {
+    /// Return the function implementing this curve.
+    pub fn as_fn(&self) -> fn(UnipolarFloat) -> UnipolarFloat {
+        match self {
+            Self::Linear => linear,
+            Self::SquareLaw => square_law,
+            Self::SCurve => s_curve,
+        }
+    }
+
+    /// Apply this curve to a fader level.
+    pub fn apply(&self, level: UnipolarFloat) -> UnipolarFloat {
+        self.as_fn()(level)
+    }
+}
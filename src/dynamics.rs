@@ -0,0 +1,232 @@
+//! Level detection and gain control, for sound-reactive control.
+use std::time::Duration;
+
+use crate::{BipolarFloat, LagFilter, UnipolarFloat};
+
+/// Follows the level of an audio-like signal, rising with the attack time
+/// constant and falling with the release time constant.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EnvelopeFollower(LagFilter);
+
+impl EnvelopeFollower {
+    /// Create a follower at rest at zero, with the provided time constants.
+    pub fn new(attack: Duration, release: Duration) -> Self {
+        Self(LagFilter::new(attack, release))
+    }
+
+    /// Return the current level.
+    pub fn level(&self) -> UnipolarFloat {
+        self.0.value()
+    }
+
+    /// Jump immediately to the provided level.
+    pub fn reset(&mut self, level: UnipolarFloat) {
+        self.0.reset(level);
+    }
+
+    pub fn set_attack(&mut self, attack: Duration) {
+        self.0.set_rise(attack);
+    }
+
+    pub fn set_release(&mut self, release: Duration) {
+        self.0.set_fall(release);
+    }
+
+    /// Incorporate a sample taken time dt after the previous one, and return
+    /// the new level.
+    pub fn update(&mut self, sample: BipolarFloat, dt: Duration) -> UnipolarFloat {
+        self.0.update(sample.abs(), dt)
+    }
+}
+
+/// A level meter which holds its peak for a while before letting it decay,
+/// with the ballistics of a typical level display.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PeakMeter {
+    hold: Duration,
+    decay: f64,
+    level: UnipolarFloat,
+    peak: UnipolarFloat,
+    /// Time since the peak was last set.
+    held: Duration,
+}
+
+impl PeakMeter {
+    /// Create a meter which holds each peak for the provided time, then
+    /// lets it fall by decay per second. Negative decay rates are clamped
+    /// to zero.
+    pub fn new(hold: Duration, decay: f64) -> Self {
+        Self {
+            hold,
+            decay: f64::max(decay, 0.0),
+            level: UnipolarFloat::ZERO,
+            peak: UnipolarFloat::ZERO,
+            held: Duration::ZERO,
+        }
+    }
+
+    /// Return the most recent level.
+    pub fn level(&self) -> UnipolarFloat {
+        self.level
+    }
+
+    /// Return the held peak level.
+    pub fn peak(&self) -> UnipolarFloat {
+        self.peak
+    }
+
+    /// Reset the level and the peak to zero.
+    pub fn reset(&mut self) {
+        self.level = UnipolarFloat::ZERO;
+        self.peak = UnipolarFloat::ZERO;
+        self.held = Duration::ZERO;
+    }
+
+    /// Incorporate a level measured time dt after the previous one, and
+    /// return the new peak.
+    pub fn update(&mut self, level: UnipolarFloat, dt: Duration) -> UnipolarFloat {
+        self.level = level;
+        let before = self.held;
+        self.held += dt;
+        if self.held > self.hold {
+            // Only decay for the part of dt which lies past the hold time.
+            let decaying = self.held - Duration::max(before, self.hold);
+            self.peak = UnipolarFloat::clamped(self.peak.0 - self.decay * decaying.as_secs_f64());
+        }
+        if level >= self.peak {
+            self.peak = level;
+            self.held = Duration::ZERO;
+        }
+        self.peak
+    }
+}
+
+/// Produces a gain which ducks one layer while a sidechain level, such as
+/// the master of another layer, is above a threshold.
+///
+/// The gain is that of a compressor acting on the sidechain: the part of
+/// the sidechain level above the threshold is divided by ratio. Gain
+/// reduction is applied with the attack time constant and recovers with the
+/// release time constant.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Gate {
+    threshold: UnipolarFloat,
+    ratio: f64,
+    gain: LagFilter,
+}
+
+impl Gate {
+    /// Create a gate with the provided parameters, at rest at full gain.
+    /// Ratios less than 1 are treated as 1 (no ducking).
+    pub fn new(threshold: UnipolarFloat, ratio: f64, attack: Duration, release: Duration) -> Self {
+        let mut gain = LagFilter::new(release, attack);
+        gain.reset(UnipolarFloat::ONE);
+        Self {
+            threshold,
+            ratio: f64::max(ratio, 1.0),
+            gain,
+        }
+    }
+
+    /// Return the current output gain.
+    pub fn gain(&self) -> UnipolarFloat {
+        self.gain.value()
+    }
+
+    pub fn set_threshold(&mut self, threshold: UnipolarFloat) {
+        self.threshold = threshold;
+    }
+
+    /// Ratios less than 1 are treated as 1 (no ducking).
+    pub fn set_ratio(&mut self, ratio: f64) {
+        self.ratio = f64::max(ratio, 1.0);
+    }
+
+    pub fn set_attack(&mut self, attack: Duration) {
+        self.gain.set_fall(attack);
+    }
+
+    pub fn set_release(&mut self, release: Duration) {
+        self.gain.set_rise(release);
+    }
+
+    /// Return the gain for the provided sidechain level, before smoothing.
+    fn target(&self, level: UnipolarFloat) -> UnipolarFloat {
+        let (l, t) = (level.0, self.threshold.0);
+        if l <= t {
+            return UnipolarFloat::ONE;
+        }
+        UnipolarFloat::new((t + (l - t) / self.ratio) / l)
+    }
+
+    /// Incorporate a sidechain level measured time dt after the previous
+    /// one, and return the new output gain.
+    pub fn update(&mut self, sidechain: UnipolarFloat, dt: Duration) -> UnipolarFloat {
+        let target = self.target(sidechain);
+        self.gain.update(target, dt)
+    }
+}
+
+/// The root-mean-square level of an audio-like signal over a sliding
+/// window of the most recent samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rms {
+    /// The squares of the samples in the window, as a ring buffer.
+    squares: Vec<f64>,
+    next: usize,
+    filled: usize,
+    sum: f64,
+}
+
+impl Rms {
+    /// Create a meter averaging over the provided number of samples.
+    ///
+    /// Panics if window is zero.
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "cannot average over an empty window");
+        Self {
+            squares: vec![0.0; window],
+            next: 0,
+            filled: 0,
+            sum: 0.0,
+        }
+    }
+
+    /// Return the number of samples averaged over.
+    pub fn window(&self) -> usize {
+        self.squares.len()
+    }
+
+    /// Return the current level, or zero if no samples have been seen.
+    pub fn level(&self) -> UnipolarFloat {
+        if self.filled == 0 {
+            return UnipolarFloat::ZERO;
+        }
+        // Guard against rounding in the running sum.
+        UnipolarFloat::new(f64::max(self.sum / self.filled as f64, 0.0).sqrt())
+    }
+
+    /// Forget every sample seen so far.
+    pub fn reset(&mut self) {
+        self.squares.iter_mut().for_each(|s| *s = 0.0);
+        self.next = 0;
+        self.filled = 0;
+        self.sum = 0.0;
+    }
+
+    /// Incorporate a sample, replacing the oldest one in the window if it
+    /// is full, and return the new level.
+    pub fn update(&mut self, sample: BipolarFloat) -> UnipolarFloat {
+        let square = sample.0 * sample.0;
+        self.sum += square - self.squares[self.next];
+        self.squares[self.next] = square;
+        self.next = (self.next + 1) % self.squares.len();
+        self.filled = usize::min(self.filled + 1, self.squares.len());
+        if self.next == 0 {
+            // Once per pass over the window, discard accumulated rounding
+            // error from the running sum.
+            self.sum = self.squares.iter().sum();
+        }
+        self.level()
+    }
+}
@@ -0,0 +1,44 @@
+//! C-callable constructors and accessors.
+//!
+//! UnipolarFloat, BipolarFloat, and Phase are `repr(transparent)` over f64,
+//! so they, and arrays of them, can be passed to C as `double` without
+//! conversion. Rgb is `repr(C)` and has the layout of `double[3]`.
+//! Use these functions to construct values from C while upholding the range
+//! invariants.
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+/// Clamp the provided value to the unit range.
+#[no_mangle]
+pub extern "C" fn number_unipolar_new(v: f64) -> UnipolarFloat {
+    UnipolarFloat::new(v)
+}
+
+/// Return the inner float value.
+#[no_mangle]
+pub extern "C" fn number_unipolar_val(v: UnipolarFloat) -> f64 {
+    v.0
+}
+
+/// Clamp the provided value to the bipolar unit range.
+#[no_mangle]
+pub extern "C" fn number_bipolar_new(v: f64) -> BipolarFloat {
+    BipolarFloat::new(v)
+}
+
+/// Return the inner float value.
+#[no_mangle]
+pub extern "C" fn number_bipolar_val(v: BipolarFloat) -> f64 {
+    v.0
+}
+
+/// Wrap the provided value into the unit range.
+#[no_mangle]
+pub extern "C" fn number_phase_new(v: f64) -> Phase {
+    Phase::new(v)
+}
+
+/// Return the inner phase.
+#[no_mangle]
+pub extern "C" fn number_phase_val(v: Phase) -> f64 {
+    v.0
+}
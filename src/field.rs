@@ -0,0 +1,212 @@
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::{error::Error, fmt};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Interpolate, UnipolarFloat};
+
+/// The number of cells provided for a Field2D did not match its size.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FieldSizeError {
+    pub width: usize,
+    pub height: usize,
+    /// The number of cells provided.
+    pub cells: usize,
+}
+
+impl fmt::Display for FieldSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a {}x{} field needs {} cells, but {} were provided",
+            self.width,
+            self.height,
+            self.width * self.height,
+            self.cells
+        )
+    }
+}
+
+impl Error for FieldSizeError {}
+
+/// A two-dimensional grid of levels, such as a pixel map or a modulation
+/// surface, which can be sampled at any point in between its cells.
+///
+/// Along an axis which does not wrap, unit coordinates span from the center
+/// of the first cell to the center of the last. Along an axis which wraps,
+/// the grid repeats, so sampling past the last cell interpolates back
+/// towards the first.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawField2D"))]
+pub struct Field2D {
+    width: usize,
+    height: usize,
+    cells: Vec<UnipolarFloat>,
+    wrap_x: bool,
+    wrap_y: bool,
+}
+
+impl Field2D {
+    /// Create a field of the provided size, filled with zeros.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![UnipolarFloat::ZERO; width * height],
+            wrap_x: false,
+            wrap_y: false,
+        }
+    }
+
+    /// Create a field from cells stored row by row.
+    /// Return an error if the number of cells does not match the size.
+    pub fn from_cells(
+        width: usize,
+        height: usize,
+        cells: Vec<UnipolarFloat>,
+    ) -> Result<Self, FieldSizeError> {
+        if cells.len() != width * height {
+            return Err(FieldSizeError {
+                width,
+                height,
+                cells: cells.len(),
+            });
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+            wrap_x: false,
+            wrap_y: false,
+        })
+    }
+
+    /// Set whether sampling wraps around along each axis.
+    pub fn with_wrap(mut self, wrap_x: bool, wrap_y: bool) -> Self {
+        self.wrap_x = wrap_x;
+        self.wrap_y = wrap_y;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Return the cells, stored row by row.
+    pub fn cells(&self) -> &[UnipolarFloat] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [UnipolarFloat] {
+        &mut self.cells
+    }
+
+    /// Return the level of a single cell, or None if it is out of bounds.
+    pub fn get(&self, x: usize, y: usize) -> Option<UnipolarFloat> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(self.cells[y * self.width + x])
+    }
+
+    /// Set the level of a single cell. Out of bounds cells are ignored.
+    pub fn set(&mut self, x: usize, y: usize, level: UnipolarFloat) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = level;
+        }
+    }
+
+    /// Return the level at the provided unit coordinates, bilinearly
+    /// interpolating between the four nearest cells.
+    /// An empty field always produces zero.
+    pub fn sample(&self, x: UnipolarFloat, y: UnipolarFloat) -> UnipolarFloat {
+        if self.cells.is_empty() {
+            return UnipolarFloat::ZERO;
+        }
+        let (x0, x1, tx) = neighbors(x, self.width, self.wrap_x);
+        let (y0, y1, ty) = neighbors(y, self.height, self.wrap_y);
+        let at = |x, y| self.cells[y * self.width + x];
+        let top = at(x0, y0).interp(at(x1, y0), tx);
+        let bottom = at(x0, y1).interp(at(x1, y1), tx);
+        top.interp(bottom, ty)
+    }
+}
+
+/// The serialized form, validated when deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawField2D {
+    width: usize,
+    height: usize,
+    cells: Vec<UnipolarFloat>,
+    wrap_x: bool,
+    wrap_y: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawField2D> for Field2D {
+    type Error = FieldSizeError;
+    fn try_from(raw: RawField2D) -> Result<Self, Self::Error> {
+        Ok(Self::from_cells(raw.width, raw.height, raw.cells)?.with_wrap(raw.wrap_x, raw.wrap_y))
+    }
+}
+
+/// Return the indices of the cells either side of unit coordinate v along
+/// an axis of len cells, and the position between them.
+fn neighbors(v: UnipolarFloat, len: usize, wrap: bool) -> (usize, usize, UnipolarFloat) {
+    if wrap {
+        let pos = v.as_phase();
+        let i = pos.sector(len);
+        (i, (i + 1) % len, UnipolarFloat(pos.sector_phase(len).0))
+    } else {
+        let pos = v.0 * (len - 1) as f64;
+        let i = usize::min(pos as usize, len - 1);
+        let next = usize::min(i + 1, len - 1);
+        (i, next, UnipolarFloat::new(pos - i as f64))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Raw {
+        width: usize,
+        height: usize,
+        cells: Vec<UnipolarFloat>,
+        wrap_x: bool,
+        wrap_y: bool,
+    }
+
+    #[test]
+    fn deserialize_rejects_mismatched_cells() {
+        let raw = Raw {
+            width: 2,
+            height: 2,
+            cells: vec![UnipolarFloat::new(0.5)],
+            wrap_x: false,
+            wrap_y: false,
+        };
+        let bytes = bincode::serialize(&raw).unwrap();
+        assert!(bincode::deserialize::<Field2D>(&bytes).is_err());
+    }
+
+    #[test]
+    fn deserialize_round_trips() {
+        let field = Field2D::from_cells(2, 1, vec![UnipolarFloat::ZERO, UnipolarFloat::ONE])
+            .unwrap()
+            .with_wrap(true, false);
+        let bytes = bincode::serialize(&field).unwrap();
+        assert_eq!(bincode::deserialize::<Field2D>(&bytes).unwrap(), field);
+    }
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use crate::{Interpolate, UnipolarFloat};
+
+/// Return the fraction of the remaining distance to a target that a
+/// one-pole smoother with time constant tau covers in time dt.
+/// A zero time constant reaches the target immediately.
+pub(crate) fn smoothing_alpha(dt: Duration, tau: Duration) -> f64 {
+    if tau.is_zero() {
+        return 1.0;
+    }
+    1.0 - (-dt.as_secs_f64() / tau.as_secs_f64()).exp()
+}
+
+/// An asymmetric smoother with independent time constants for rising and
+/// falling levels, such as to emulate the thermal lag of a tungsten lamp.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LagFilter {
+    rise: Duration,
+    fall: Duration,
+    value: UnipolarFloat,
+}
+
+impl LagFilter {
+    /// Create a filter at rest at zero, with the provided time constants.
+    /// The time constant is the time taken to cover about 63% of a step.
+    pub fn new(rise: Duration, fall: Duration) -> Self {
+        Self {
+            rise,
+            fall,
+            value: UnipolarFloat::ZERO,
+        }
+    }
+
+    /// Return the current output level.
+    pub fn value(&self) -> UnipolarFloat {
+        self.value
+    }
+
+    /// Jump immediately to the provided level.
+    pub fn reset(&mut self, value: UnipolarFloat) {
+        self.value = value;
+    }
+
+    pub fn set_rise(&mut self, rise: Duration) {
+        self.rise = rise;
+    }
+
+    pub fn set_fall(&mut self, fall: Duration) {
+        self.fall = fall;
+    }
+
+    /// Move the output towards the target level over time dt, and return
+    /// the new output level.
+    pub fn update(&mut self, target: UnipolarFloat, dt: Duration) -> UnipolarFloat {
+        let tau = if target > self.value {
+            self.rise
+        } else {
+            self.fall
+        };
+        let alpha = smoothing_alpha(dt, tau);
+        self.value = UnipolarFloat::new(self.value.0 + (target.0 - self.value.0) * alpha);
+        self.value
+    }
+}
+
+/// An exponential moving average, for smoothing noisy control input.
+/// Works with any interpolable value; phases are averaged around the circle
+/// along the shortest path, so a smoothed phase never sweeps the long way
+/// around through the wrap point.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ema<T> {
+    tau: Duration,
+    value: T,
+}
+
+impl<T: Interpolate> Ema<T> {
+    /// Create a smoother starting at the provided value, with time constant
+    /// tau. The time constant is the time taken to cover about 63% of a step.
+    pub fn new(tau: Duration, initial: T) -> Self {
+        Self {
+            tau,
+            value: initial,
+        }
+    }
+
+    /// Return the current smoothed value.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Jump immediately to the provided value.
+    pub fn reset(&mut self, value: T) {
+        self.value = value;
+    }
+
+    pub fn tau(&self) -> Duration {
+        self.tau
+    }
+
+    pub fn set_tau(&mut self, tau: Duration) {
+        self.tau = tau;
+    }
+
+    /// Incorporate a new sample taken time dt after the previous one, and
+    /// return the new smoothed value.
+    pub fn update(&mut self, value: T, dt: Duration) -> T {
+        let alpha = UnipolarFloat::new(smoothing_alpha(dt, self.tau));
+        self.value = self.value.interp(value, alpha);
+        self.value
+    }
+}
@@ -0,0 +1,121 @@
+use std::{fmt, iter::repeat_n};
+
+#[cfg(not(feature = "derive_more"))]
+use crate::PhaseOffset;
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum BarKind {
+    Unipolar,
+    Bipolar,
+    Phase,
+}
+
+/// A compact text rendering of a value as a bar graph, for dashboards and
+/// debug output. The numeric value follows the bar; format precision
+/// applies to it, defaulting to 2 decimal places.
+///
+/// Unipolar values fill from the left: `[####------] 0.40`
+/// Bipolar values fill out from the center: `[---##|-----] -0.40`
+/// Phases show a marker at their position: `[----o-----] 0.45`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bar {
+    kind: BarKind,
+    value: f64,
+    width: usize,
+}
+
+impl fmt::Display for Bar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let w = self.width;
+        let filled = |fraction: f64, cells: usize| {
+            usize::min((fraction * cells as f64).round() as usize, cells)
+        };
+        let mut bar = String::with_capacity(w + 3);
+        bar.push('[');
+        match self.kind {
+            BarKind::Unipolar => {
+                let n = filled(self.value, w);
+                bar.extend(repeat_n('#', n));
+                bar.extend(repeat_n('-', w - n));
+            }
+            BarKind::Bipolar => {
+                let (left, right) = (w / 2, w - w / 2);
+                let neg = filled(-self.value, left);
+                let pos = filled(self.value, right);
+                bar.extend(repeat_n('-', left - neg));
+                bar.extend(repeat_n('#', neg));
+                bar.push('|');
+                bar.extend(repeat_n('#', pos));
+                bar.extend(repeat_n('-', right - pos));
+            }
+            BarKind::Phase => {
+                let marker = usize::min((self.value * w as f64) as usize, w.saturating_sub(1));
+                for i in 0..w {
+                    bar.push(if i == marker { 'o' } else { '-' });
+                }
+            }
+        }
+        bar.push(']');
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "{} {:.*}", bar, precision, self.value)
+    }
+}
+
+impl UnipolarFloat {
+    /// Render this value as a bar graph of the provided width in characters.
+    pub fn bar(&self, width: usize) -> Bar {
+        Bar {
+            kind: BarKind::Unipolar,
+            value: self.0,
+            width,
+        }
+    }
+}
+
+impl BipolarFloat {
+    /// Render this value as a bar graph of the provided width in characters,
+    /// not including the center marker.
+    pub fn bar(&self, width: usize) -> Bar {
+        Bar {
+            kind: BarKind::Bipolar,
+            value: self.0,
+            width,
+        }
+    }
+}
+
+impl Phase {
+    /// Render this phase as a position marker in a bar of the provided width
+    /// in characters.
+    pub fn bar(&self, width: usize) -> Bar {
+        Bar {
+            kind: BarKind::Phase,
+            value: self.0,
+            width,
+        }
+    }
+}
+
+/// Format the inner float value, standing in for the derive_more derives.
+#[cfg(not(feature = "derive_more"))]
+macro_rules! impl_fmt {
+    ($t:ident, $($trait:ident),*) => {
+        $(
+            impl fmt::$trait for $t {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$trait::fmt(&self.val(), f)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "derive_more"))]
+impl_fmt!(UnipolarFloat, Display, LowerExp, UpperExp);
+#[cfg(not(feature = "derive_more"))]
+impl_fmt!(BipolarFloat, Display, LowerExp, UpperExp);
+#[cfg(not(feature = "derive_more"))]
+impl_fmt!(Phase, Display, LowerExp, UpperExp);
+#[cfg(not(feature = "derive_more"))]
+impl_fmt!(PhaseOffset, Display);
@@ -0,0 +1,52 @@
+//! Conversions for passing values into graphics code, such as GPU uniform
+//! buffers for visualization shaders.
+use crate::{BipolarFloat, UnipolarFloat};
+
+impl UnipolarFloat {
+    /// Return the inner value at single precision, as shaders expect.
+    pub fn as_f32(&self) -> f32 {
+        self.0 as f32
+    }
+}
+
+impl BipolarFloat {
+    /// Return the inner value at single precision, as shaders expect.
+    pub fn as_f32(&self) -> f32 {
+        self.0 as f32
+    }
+}
+
+/// Pack a pair of bipolar values into a mint vector.
+#[cfg(feature = "mint")]
+pub fn to_mint(x: BipolarFloat, y: BipolarFloat) -> mint::Vector2<f32> {
+    mint::Vector2 {
+        x: x.as_f32(),
+        y: y.as_f32(),
+    }
+}
+
+/// Unpack a mint vector into a pair of bipolar values, clamping each
+/// component into range.
+#[cfg(feature = "mint")]
+pub fn from_mint(v: mint::Vector2<f32>) -> (BipolarFloat, BipolarFloat) {
+    (
+        BipolarFloat::new(f64::from(v.x)),
+        BipolarFloat::new(f64::from(v.y)),
+    )
+}
+
+/// Pack a pair of bipolar values into a glam vector.
+#[cfg(feature = "glam")]
+pub fn to_vec2(x: BipolarFloat, y: BipolarFloat) -> glam::Vec2 {
+    glam::Vec2::new(x.as_f32(), y.as_f32())
+}
+
+/// Unpack a glam vector into a pair of bipolar values, clamping each
+/// component into range.
+#[cfg(feature = "glam")]
+pub fn from_vec2(v: glam::Vec2) -> (BipolarFloat, BipolarFloat) {
+    (
+        BipolarFloat::new(f64::from(v.x)),
+        BipolarFloat::new(f64::from(v.y)),
+    )
+}
@@ -0,0 +1,36 @@
+use half::f16;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::UnipolarFloat;
+
+/// A half-precision storage type for unipolar values, for large buffers
+/// where memory bandwidth matters more than precision.
+/// Convert to UnipolarFloat to perform arithmetic.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub struct UnipolarF16(f16);
+
+impl UnipolarF16 {
+    pub const ZERO: Self = Self(f16::ZERO);
+    pub const ONE: Self = Self(f16::ONE);
+
+    /// Return the inner half-precision value.
+    pub fn val(&self) -> f16 {
+        self.0
+    }
+}
+
+impl From<UnipolarFloat> for UnipolarF16 {
+    /// Round to the nearest representable half-precision value.
+    fn from(value: UnipolarFloat) -> Self {
+        Self(f16::from_f64(value.val()))
+    }
+}
+
+impl From<UnipolarF16> for UnipolarFloat {
+    fn from(value: UnipolarF16) -> Self {
+        UnipolarFloat::new(value.0.to_f64())
+    }
+}
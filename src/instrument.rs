@@ -0,0 +1,67 @@
+//! Observability for silent clamping.
+//!
+//! Every time a UnipolarFloat or BipolarFloat has to clamp an out-of-range
+//! input, either during construction or arithmetic, a global counter is
+//! incremented and the registered hook, if any, is called.
+//!
+//! Only values which arrive out of range are reported: inputs to the
+//! constructors and the results of arithmetic operators. Functions in this
+//! crate which saturate as part of their documented behavior, such as
+//! curves which overshoot, mixes which sum past full, or levels which fall
+//! off to zero, clamp their results with `clamped` and are never reported.
+//! Nor are guards against floating point rounding.
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    RwLock,
+};
+
+/// Describes a value that had to be clamped into range.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ClampEvent {
+    /// The out-of-range input.
+    pub value: f64,
+    /// The lower bound of the range the value was clamped into.
+    pub min: f64,
+    /// The upper bound of the range the value was clamped into.
+    pub max: f64,
+}
+
+static CLAMP_COUNT: AtomicU64 = AtomicU64::new(0);
+static CLAMP_HOOK: RwLock<Option<fn(ClampEvent)>> = RwLock::new(None);
+
+/// Return the number of times a value has been clamped since the program
+/// started, or since the last call to reset_clamp_count.
+pub fn clamp_count() -> u64 {
+    CLAMP_COUNT.load(Ordering::Relaxed)
+}
+
+/// Reset the clamp counter to zero.
+pub fn reset_clamp_count() {
+    CLAMP_COUNT.store(0, Ordering::Relaxed);
+}
+
+/// Register a function to call every time a value is clamped, replacing any
+/// previously registered hook. Pass None to remove the hook.
+/// The hook may be called from any thread.
+pub fn set_clamp_hook(hook: Option<fn(ClampEvent)>) {
+    // A poisoned lock only means a hook panicked; the stored value is fine.
+    *CLAMP_HOOK.write().unwrap_or_else(|e| e.into_inner()) = hook;
+}
+
+/// Record a clamp event if the value lies outside of [min, max].
+/// NaN is considered out of range.
+#[inline]
+pub(crate) fn observe(value: f64, min: f64, max: f64) {
+    if !(value >= min && value <= max) {
+        record(ClampEvent { value, min, max });
+    }
+}
+
+#[cold]
+fn record(event: ClampEvent) {
+    CLAMP_COUNT.fetch_add(1, Ordering::Relaxed);
+    let hook = *CLAMP_HOOK.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(hook) = hook {
+        hook(event);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{BipolarFloat, Phase, Rgb, UnipolarFloat};
+
+/// Types which can be interpolated between two values.
+pub trait Interpolate: Copy {
+    /// Interpolate from self to other; t = 0 is self and t = 1 is other.
+    fn interp(self, other: Self, t: UnipolarFloat) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interp(self, other: Self, t: UnipolarFloat) -> Self {
+        self + (other - self) * t.0
+    }
+}
+
+impl Interpolate for UnipolarFloat {
+    fn interp(self, other: Self, t: UnipolarFloat) -> Self {
+        // Clamp to guard against rounding at the endpoints.
+        Self::clamped(self.0.interp(other.0, t))
+    }
+}
+
+impl Interpolate for BipolarFloat {
+    fn interp(self, other: Self, t: UnipolarFloat) -> Self {
+        // Clamp to guard against rounding at the endpoints.
+        Self::clamped(self.0.interp(other.0, t))
+    }
+}
+
+impl Interpolate for Phase {
+    /// Move around the circle in whichever direction is shortest.
+    /// If the phases are exactly opposite, move backwards.
+    fn interp(self, other: Self, t: UnipolarFloat) -> Self {
+        self + self.distance_to(other) * t.0
+    }
+}
+
+impl Interpolate for Rgb {
+    fn interp(self, other: Self, t: UnipolarFloat) -> Self {
+        self.lerp(&other, t)
+    }
+}
+
+/// Evaluate the cubic Hermite curve from p0 to p1 with tangents m0 and m1 at
+/// the ends, at position t.
+pub(crate) fn hermite_f64(p0: f64, p1: f64, m0: f64, m1: f64, t: f64) -> f64 {
+    let (t2, t3) = (t * t, t * t * t);
+    (2.0 * t3 - 3.0 * t2 + 1.0) * p0
+        + (t3 - 2.0 * t2 + t) * m0
+        + (-2.0 * t3 + 3.0 * t2) * p1
+        + (t3 - t2) * m1
+}
+
+/// Smoothly interpolate from p0 to p1 along a cubic Hermite curve, leaving
+/// p0 with tangent m0 and arriving at p1 with tangent m1.
+/// Steep tangents can make the curve overshoot; the result is clamped back
+/// into range.
+pub fn hermite(
+    p0: UnipolarFloat,
+    p1: UnipolarFloat,
+    m0: f64,
+    m1: f64,
+    t: UnipolarFloat,
+) -> UnipolarFloat {
+    UnipolarFloat::clamped(hermite_f64(p0.0, p1.0, m0, m1, t.0))
+}
@@ -1,14 +1,128 @@
 use std::{
     cmp::Ordering,
-    ops::{Add, AddAssign, Div, Mul, MulAssign, Sub},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, RangeInclusive, Sub, SubAssign},
+    time::{Duration, Instant},
 };
 
-use derive_more::Display;
+#[cfg(feature = "derive_more")]
+use derive_more::{Display, LowerExp, UpperExp};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+mod angle;
+mod apply;
+mod atomic;
+pub mod blend;
+mod bounded;
+mod buffer;
+mod chase;
+mod checked;
+mod color;
+#[cfg(feature = "serde")]
+pub mod compact;
+mod crossfade;
+#[cfg(feature = "defmt")]
+mod defmt_format;
+pub mod dimming;
+mod dynamics;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod field;
+mod filter;
+mod format;
+#[cfg(any(feature = "mint", feature = "glam"))]
+pub mod gpu;
+#[cfg(feature = "half")]
+mod half_float;
+#[cfg(feature = "clamp-hooks")]
+pub mod instrument;
+mod interpolate;
+mod master;
+mod mix;
+mod newtype;
+#[cfg(feature = "num-traits")]
+mod num;
+mod offset;
+#[cfg(feature = "osc")]
+mod osc;
+mod pan;
+mod parse;
+mod policy;
+#[cfg(feature = "python")]
+mod python;
+mod ramp;
+#[cfg(feature = "rand")]
+mod random;
+mod range;
+mod sample_hold;
+#[cfg(feature = "schemars")]
+mod schema;
+mod sequence;
+mod spline;
+#[cfg(any(feature = "sqlx", feature = "postgres"))]
+mod sql;
+mod stats;
+mod strobe;
+mod tempo;
+mod tracker;
+mod trigger;
+mod tween;
+mod unit;
+mod vector;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod wavetable;
+#[cfg(feature = "egui")]
+pub mod widgets;
+
+pub use angle::Angle;
+pub use atomic::{AtomicBipolar, AtomicUnipolar};
+pub use bounded::{BoundedFloat, InvalidBounds};
+pub use buffer::UnipolarBuffer;
+pub use chase::{distribute, distribute_into, Chase, ChaseDirection};
+pub use color::{hsv_to_rgb, Hue, Rgb};
+pub use crossfade::{Crossfader, FadeCurve};
+pub use dynamics::{EnvelopeFollower, Gate, PeakMeter, Rms};
+pub use field::{Field2D, FieldSizeError};
+pub use filter::{Ema, LagFilter};
+pub use format::Bar;
+#[cfg(feature = "half")]
+pub use half_float::UnipolarF16;
+pub use interpolate::{hermite, Interpolate};
+pub use master::MasterChain;
+pub use mix::{blend, htp, htp_slice, ltp, ltp_slice, normalize_peak, normalize_sum, softmax, Ltp};
+pub use offset::PhaseOffset;
+#[cfg(feature = "osc")]
+pub use osc::OscConversionError;
+pub use pan::{circular_pan, circular_pan_into, mid_side_decode, mid_side_encode, pan, PanLaw};
+pub use parse::ParseError;
+pub use policy::{RangeError, RangePolicy};
+pub use ramp::{Ramp, RampMode};
+pub use range::UnipolarRange;
+pub use sample_hold::SampleHold;
+pub use sequence::StepSequence;
+pub use spline::Spline;
+pub use stats::{histogram, median, percentile, Stats};
+pub use strobe::{strobe, Strobe};
+pub use tempo::Tempo;
+pub use tracker::{FrequencyEstimator, PhaseTracker};
+pub use trigger::{Edge, Trigger};
+pub use tween::{Easing, Tween};
+pub use unit::UnitValue;
+pub use vector::{Bipolar2, Polar};
+pub use wavetable::wavetable_read;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+
 /// A float type constrained to the range [0.0, 1.0].
 /// The type upholds the range invariant by clamping the value to the range.
-#[derive(Display, Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "derive_more", derive(Display, LowerExp, UpperExp))]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
 pub struct UnipolarFloat(f64);
 
 impl UnipolarFloat {
@@ -16,13 +130,40 @@ impl UnipolarFloat {
     pub const ONE: Self = Self(1.0);
 
     /// Clamp the provided value to the unit range.
+    #[inline]
     pub fn new(v: f64) -> Self {
         let mut uf = Self(v);
         uf.clamp();
         uf
     }
 
+    /// Wrap the provided value without clamping it.
+    /// The caller must ensure the value is in range; this is checked in
+    /// debug builds only.
+    pub fn new_unchecked(v: f64) -> Self {
+        debug_assert!(
+            (0.0..=1.0).contains(&v),
+            "{} is out of range for UnipolarFloat",
+            v
+        );
+        Self(v)
+    }
+
+    /// Smoothly map any real number into the unit range using the logistic
+    /// function. 0 maps to 0.5; larger steepness approaches the ends faster.
+    pub fn sigmoid(x: f64, steepness: f64) -> Self {
+        Self::new(1.0 / (1.0 + (-steepness * x).exp()))
+    }
+
+    /// Linearly map a value from the provided range into the unit range,
+    /// clamping the result.
+    /// from.start() -> 0.0, from.end() -> 1.0
+    pub fn remap_from(v: f64, from: RangeInclusive<f64>) -> Self {
+        Self::new(remap(v, from, 0.0..=1.0))
+    }
+
     /// Return the inner float value.
+    #[inline]
     pub fn val(&self) -> f64 {
         self.0
     }
@@ -32,6 +173,11 @@ impl UnipolarFloat {
         Self(1.0 - self.0)
     }
 
+    /// Return how much could be added to this value before it clips at 1.
+    pub fn headroom(&self) -> f64 {
+        1.0 - self.0
+    }
+
     /// Return this value as a Phase.
     pub fn as_phase(&self) -> Phase {
         // Phase and Unipolar have the same domain, no need to check.
@@ -44,7 +190,78 @@ impl UnipolarFloat {
         BipolarFloat((self.0 * 2.0) - 1.0)
     }
 
+    /// Map this value onto an index into a collection of length len, such as
+    /// for picking a preset by fader position. Each index covers an equal
+    /// share of the range; 1.0 maps to the last index.
+    ///
+    /// Panics if len is zero.
+    pub fn to_index(&self, len: usize) -> usize {
+        assert!(len > 0, "cannot index into an empty collection");
+        // Guard against 1.0 and rounding up past the last index.
+        usize::min((self.0 * len as f64) as usize, len - 1)
+    }
+
+    /// Select the element of slice at the index given by this value.
+    /// Return None if the slice is empty.
+    pub fn select<'a, T>(&self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+        slice.get(self.to_index(slice.len()))
+    }
+
+    /// Convert this level into a PWM duty cycle, as a count of timer ticks
+    /// out of period_ticks, rounding to the nearest tick with halfway cases
+    /// rounding up.
+    /// 0.0 produces 0 ticks (always off) and 1.0 produces period_ticks
+    /// (always on).
+    pub fn to_pwm_ticks(&self, period_ticks: u32) -> u32 {
+        (self.0 * f64::from(period_ticks)).round() as u32
+    }
+
+    /// Convert a PWM duty cycle in timer ticks back into a level.
+    /// Tick counts greater than the period clamp to 1.0, and a zero period
+    /// produces 0.0.
+    pub fn from_pwm_ticks(ticks: u32, period_ticks: u32) -> Self {
+        if period_ticks == 0 {
+            return Self::ZERO;
+        }
+        Self::new(f64::from(ticks) / f64::from(period_ticks))
+    }
+
+    /// Map this level onto a pulse width between min and max, such as for
+    /// driving an RC servo or ESC with 1000-2000 µs pulses.
+    /// Pulse widths too long to represent saturate at Duration::MAX.
+    pub fn to_pulse_width(&self, min: Duration, max: Duration) -> Duration {
+        let (min, max) = (min.as_secs_f64(), max.as_secs_f64());
+        let width = f64::max(min + (max - min) * self.0, 0.0);
+        Duration::try_from_secs_f64(width).unwrap_or(Duration::MAX)
+    }
+
+    /// Apply a soft-knee compressor transfer curve to this value.
+    /// Values below the threshold pass through unchanged; above it, the
+    /// excess is divided by ratio. The knee is the width of the region
+    /// around the threshold over which the curve eases between the two.
+    /// Ratios less than 1 are treated as 1 (no compression).
+    pub fn compress(&self, threshold: UnipolarFloat, ratio: f64, knee: UnipolarFloat) -> Self {
+        let (x, t, w) = (self.0, threshold.0, knee.0);
+        let slope = 1.0 / f64::max(ratio, 1.0);
+        let over = x - t;
+        let y = if 2.0 * over < -w {
+            x
+        } else if 2.0 * over.abs() <= w && w > 0.0 {
+            let k = over + w / 2.0;
+            x + (slope - 1.0) * k * k / (2.0 * w)
+        } else {
+            t + over * slope
+        };
+        Self::new(y)
+    }
+
+    #[inline]
     fn clamp(&mut self) {
+        #[cfg(feature = "clamp-hooks")]
+        instrument::observe(self.0, 0.0, 1.0);
         clamp(&mut self.0, 0.0, 1.0);
     }
 }
@@ -102,6 +319,23 @@ impl MulAssign<UnipolarFloat> for f64 {
     }
 }
 
+impl MulAssign<f64> for UnipolarFloat {
+    /// Multiply self by other and clamp.
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = Self::new(self.0 * rhs);
+    }
+}
+
+impl DivAssign<f64> for UnipolarFloat {
+    /// Divide self by other and clamp.
+    /// Division by zero saturates to 1.0, or 0.0 if self is also zero.
+    fn div_assign(&mut self, rhs: f64) {
+        // Treat -0.0 like 0.0, so the sign of a zero divisor does not matter.
+        let rhs = if rhs == 0.0 { 0.0 } else { rhs };
+        *self = Self::new(self.0 / rhs);
+    }
+}
+
 impl Sub for UnipolarFloat {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
@@ -109,6 +343,28 @@ impl Sub for UnipolarFloat {
     }
 }
 
+impl Sub<f64> for UnipolarFloat {
+    type Output = Self;
+    /// Subtract other from self and clamp.
+    fn sub(self, rhs: f64) -> Self::Output {
+        Self::new(self.0 - rhs)
+    }
+}
+
+impl SubAssign for UnipolarFloat {
+    /// Subtract other from self and clamp.
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= rhs.val();
+    }
+}
+
+impl SubAssign<f64> for UnipolarFloat {
+    /// Subtract other from self and clamp.
+    fn sub_assign(&mut self, rhs: f64) {
+        *self = Self::new(self.0 - rhs);
+    }
+}
+
 impl Add for UnipolarFloat {
     type Output = Self;
     /// Add other to self and clamp.
@@ -117,6 +373,14 @@ impl Add for UnipolarFloat {
     }
 }
 
+impl Add<f64> for UnipolarFloat {
+    type Output = Self;
+    /// Add other to self and clamp.
+    fn add(self, rhs: f64) -> Self::Output {
+        Self::new(self.0 + rhs)
+    }
+}
+
 impl AddAssign for UnipolarFloat {
     /// Add other to self and clamp.
     fn add_assign(&mut self, rhs: Self) {
@@ -133,7 +397,11 @@ impl AddAssign<f64> for UnipolarFloat {
 
 // A float type constrained to the range [-1.0, 1.0].
 /// The type upholds the range invariant by clamping the value to the range.
-#[derive(Display, Debug, Copy, Clone, PartialEq, PartialOrd, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "derive_more", derive(Display, LowerExp, UpperExp))]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
 pub struct BipolarFloat(f64);
 
 impl BipolarFloat {
@@ -141,13 +409,41 @@ impl BipolarFloat {
     pub const ONE: Self = Self(1.0);
 
     /// Clamp the provided value to the bipolar unit range.
+    #[inline]
     pub fn new(v: f64) -> Self {
         let mut bf = Self(v);
         bf.clamp();
         bf
     }
 
+    /// Wrap the provided value without clamping it.
+    /// The caller must ensure the value is in range; this is checked in
+    /// debug builds only.
+    pub fn new_unchecked(v: f64) -> Self {
+        debug_assert!(
+            (-1.0..=1.0).contains(&v),
+            "{} is out of range for BipolarFloat",
+            v
+        );
+        Self(v)
+    }
+
+    /// Softly limit any real number into the bipolar range using tanh.
+    /// Small inputs are scaled by drive almost linearly; large inputs
+    /// approach the ends of the range without a hard corner.
+    pub fn soft_clip(x: f64, drive: f64) -> Self {
+        Self::new((drive * x).tanh())
+    }
+
+    /// Linearly map a value from the provided range into the bipolar range,
+    /// clamping the result.
+    /// from.start() -> -1.0, from.end() -> 1.0
+    pub fn remap_from(v: f64, from: RangeInclusive<f64>) -> Self {
+        Self::new(remap(v, from, -1.0..=1.0))
+    }
+
     /// Return the inner float value.
+    #[inline]
     pub fn val(&self) -> f64 {
         self.0
     }
@@ -159,7 +455,7 @@ impl BipolarFloat {
 
     /// Return the negation of this value.
     pub fn invert(&self) -> Self {
-        Self(-1.0 * self.0)
+        Self(-self.0)
     }
 
     /// Conditionally return the negation of this value.
@@ -171,17 +467,95 @@ impl BipolarFloat {
         }
     }
 
+    /// Return how far this value is from each end of the range, as the
+    /// distance down to -1 followed by the distance up to 1.
+    pub fn margin(&self) -> (f64, f64) {
+        (self.0 + 1.0, 1.0 - self.0)
+    }
+
+    /// Return the positive part of this value; negative values map to 0.
+    pub fn rectify(&self) -> UnipolarFloat {
+        UnipolarFloat(f64::max(self.0, 0.0))
+    }
+
+    /// Return the sign of this value.
+    pub fn sign(&self) -> Sign {
+        if self.0 > 0.0 {
+            Sign::Positive
+        } else if self.0 < 0.0 {
+            Sign::Negative
+        } else {
+            Sign::Zero
+        }
+    }
+
+    /// Split this value into a magnitude and a direction.
+    pub fn magnitude_sign(&self) -> (UnipolarFloat, Sign) {
+        (self.abs(), self.sign())
+    }
+
+    /// Return 1.0 if this value is positive, -1.0 if it is negative, and
+    /// 0.0 if it is zero.
+    /// Unlike f64::signum, zero of either sign maps to 0.0.
+    pub fn signum(&self) -> Self {
+        match self.sign() {
+            Sign::Positive => Self(1.0),
+            Sign::Negative => Self(-1.0),
+            Sign::Zero => Self(0.0),
+        }
+    }
+
+    /// Map this value onto a pitch or speed multiplier, spanning
+    /// octave_range octaves in each direction.
+    /// 0 maps to 1, 1 maps to 2^octave_range, and -1 to its reciprocal.
+    pub fn to_pitch_ratio(&self, octave_range: f64) -> f64 {
+        (self.0 * octave_range).exp2()
+    }
+
+    /// Convert a pitch or speed multiplier back into a value, as for
+    /// to_pitch_ratio. Ratios beyond the octave range are clamped, and with
+    /// a positive octave range, ratios of zero or less map to -1. A zero
+    /// octave range maps to 0.
+    pub fn from_pitch_ratio(ratio: f64, octave_range: f64) -> Self {
+        if octave_range == 0.0 {
+            return Self::ZERO;
+        }
+        Self::new(ratio.log2() / octave_range)
+    }
+
+    /// Map this value onto a pulse width, such as for driving an RC servo.
+    /// 0 maps to center, and -1 and 1 map to center minus and plus range,
+    /// so a center of 1500 µs and range of 500 µs produces 1000-2000 µs.
+    /// Pulse widths that would be negative are truncated to zero, and those
+    /// too long to represent saturate at Duration::MAX.
+    pub fn to_pulse_width(&self, center: Duration, range: Duration) -> Duration {
+        let width = center.as_secs_f64() + range.as_secs_f64() * self.0;
+        Duration::try_from_secs_f64(f64::max(width, 0.0)).unwrap_or(Duration::MAX)
+    }
+
     /// Rescale this bipolar number into a unipolar number.
     /// -1 -> 0, 0 -> 0.5, 1.0 -> 1.0
     pub fn rescale_as_unipolar(&self) -> UnipolarFloat {
         UnipolarFloat((self.0 + 1.0) / 2.0)
     }
 
+    #[inline]
     fn clamp(&mut self) {
+        #[cfg(feature = "clamp-hooks")]
+        instrument::observe(self.0, -1.0, 1.0);
         clamp(&mut self.0, -1.0, 1.0);
     }
 }
 
+/// The direction of a BipolarFloat.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
 impl PartialEq<f64> for BipolarFloat {
     fn eq(&self, other: &f64) -> bool {
         self.0.eq(other)
@@ -194,6 +568,45 @@ impl PartialOrd<f64> for BipolarFloat {
     }
 }
 
+// The comparisons between unipolar and bipolar values compare the inner
+// values directly, without rescaling either range onto the other.
+
+impl PartialEq<UnipolarFloat> for BipolarFloat {
+    fn eq(&self, other: &UnipolarFloat) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl PartialOrd<UnipolarFloat> for BipolarFloat {
+    fn partial_cmp(&self, other: &UnipolarFloat) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl PartialEq<BipolarFloat> for UnipolarFloat {
+    fn eq(&self, other: &BipolarFloat) -> bool {
+        self.0.eq(&other.0)
+    }
+}
+
+impl PartialOrd<BipolarFloat> for UnipolarFloat {
+    fn partial_cmp(&self, other: &BipolarFloat) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl PartialEq<BipolarFloat> for f64 {
+    fn eq(&self, other: &BipolarFloat) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl PartialOrd<BipolarFloat> for f64 {
+    fn partial_cmp(&self, other: &BipolarFloat) -> Option<Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
 impl From<BipolarFloat> for f64 {
     fn from(value: BipolarFloat) -> Self {
         value.0
@@ -208,6 +621,14 @@ impl Mul<UnipolarFloat> for BipolarFloat {
     }
 }
 
+impl Mul<BipolarFloat> for UnipolarFloat {
+    type Output = BipolarFloat;
+    fn mul(self, rhs: BipolarFloat) -> Self::Output {
+        // This cannot go out of range so no need to clamp.
+        BipolarFloat(self.0 * rhs.0)
+    }
+}
+
 impl Mul for BipolarFloat {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
@@ -216,6 +637,13 @@ impl Mul for BipolarFloat {
     }
 }
 
+impl Mul<f64> for BipolarFloat {
+    type Output = f64;
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.0 * rhs
+    }
+}
+
 impl Mul<BipolarFloat> for f64 {
     type Output = Self;
     fn mul(self, rhs: BipolarFloat) -> Self::Output {
@@ -223,6 +651,48 @@ impl Mul<BipolarFloat> for f64 {
     }
 }
 
+impl MulAssign for BipolarFloat {
+    fn mul_assign(&mut self, rhs: Self) {
+        // This cannot go out of range so no need to clamp.
+        self.0 *= rhs.val();
+    }
+}
+
+impl MulAssign<UnipolarFloat> for BipolarFloat {
+    fn mul_assign(&mut self, rhs: UnipolarFloat) {
+        // This cannot go out of range so no need to clamp.
+        self.0 *= rhs.val();
+    }
+}
+
+impl MulAssign<f64> for BipolarFloat {
+    /// Multiply self by other and clamp.
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = Self::new(self.0 * rhs);
+    }
+}
+
+impl MulAssign<BipolarFloat> for f64 {
+    fn mul_assign(&mut self, rhs: BipolarFloat) {
+        *self *= rhs.val();
+    }
+}
+
+impl DivAssign<f64> for BipolarFloat {
+    /// Divide self by other and clamp.
+    /// Division by zero saturates to the bound matching the sign of self,
+    /// or 0.0 if self is also zero.
+    fn div_assign(&mut self, rhs: f64) {
+        if rhs == 0.0 && self.0 == 0.0 {
+            *self = Self::ZERO;
+            return;
+        }
+        // Treat -0.0 like 0.0, so the sign of a zero divisor does not matter.
+        let rhs = if rhs == 0.0 { 0.0 } else { rhs };
+        *self = Self::new(self.0 / rhs);
+    }
+}
+
 impl Sub for BipolarFloat {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
@@ -230,6 +700,28 @@ impl Sub for BipolarFloat {
     }
 }
 
+impl Sub<f64> for BipolarFloat {
+    type Output = Self;
+    /// Subtract other from self and clamp.
+    fn sub(self, rhs: f64) -> Self::Output {
+        Self::new(self.0 - rhs)
+    }
+}
+
+impl SubAssign for BipolarFloat {
+    /// Subtract other from self and clamp.
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= rhs.val();
+    }
+}
+
+impl SubAssign<f64> for BipolarFloat {
+    /// Subtract other from self and clamp.
+    fn sub_assign(&mut self, rhs: f64) {
+        *self = Self::new(self.0 - rhs);
+    }
+}
+
 impl Add for BipolarFloat {
     type Output = Self;
     /// Add other to self and clamp.
@@ -238,6 +730,14 @@ impl Add for BipolarFloat {
     }
 }
 
+impl Add<f64> for BipolarFloat {
+    type Output = Self;
+    /// Add other to self and clamp.
+    fn add(self, rhs: f64) -> Self::Output {
+        Self::new(self.0 + rhs)
+    }
+}
+
 impl AddAssign for BipolarFloat {
     /// Add other to self and clamp.
     fn add_assign(&mut self, rhs: Self) {
@@ -252,14 +752,44 @@ impl AddAssign<f64> for BipolarFloat {
     }
 }
 
+/// Clamp v into [min, max].
+/// This deliberately avoids f64::clamp, which propagates NaN; f64::max
+/// discards a NaN argument, so NaN is clamped to min.
+#[inline]
 fn clamp(v: &mut f64, min: f64, max: f64) {
-    *v = f64::min(f64::max(*v, min), max)
+    *v = v.max(min).min(max)
+}
+
+/// Return the signed error between a follower phase and the reference it
+/// should track, along the shortest path around the circle.
+/// Positive values mean the follower is behind and should speed up; a full
+/// half-rotation of error maps to -1.
+pub fn phase_error(reference: Phase, follower: Phase) -> BipolarFloat {
+    BipolarFloat::new(follower.distance_to(reference).val() * 2.0)
+}
+
+/// Linearly map a value from one range into another.
+/// The result is not clamped; values outside of from map outside of to.
+/// Either range may be reversed. If from is empty (start == end), return
+/// the start of to.
+pub fn remap(value: f64, from: RangeInclusive<f64>, to: RangeInclusive<f64>) -> f64 {
+    let (from_start, from_end) = from.into_inner();
+    let (to_start, to_end) = to.into_inner();
+    let from_span = from_end - from_start;
+    if from_span == 0.0 {
+        return to_start;
+    }
+    to_start + (value - from_start) / from_span * (to_end - to_start)
 }
 
 /// Phase represents a unit angular phase (on the range [0.0, 1.0]).
 /// Phase upholds the invariant that the valye contained inside is always in
 /// range via wrapping the phase using euclidean modulus.
-#[derive(Debug, PartialOrd, Copy, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "derive_more", derive(Display, LowerExp, UpperExp))]
+#[derive(Debug, PartialOrd, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
 pub struct Phase(f64);
 
 impl Phase {
@@ -269,14 +799,57 @@ impl Phase {
     /// an acceptable value for phase and is useful for certain circumstances.
     pub const ONE: Self = Self(1.0);
 
+    #[inline]
     pub fn new(v: f64) -> Self {
         let mut p = Self(v);
         p.wrap();
         p
     }
 
+    /// Return the phase of a cycle with the provided period, the provided
+    /// time after the start of the first cycle.
+    /// The computation is performed on integer nanoseconds, so precision
+    /// does not degrade over long elapsed times.
+    /// A zero period produces a phase of zero.
+    pub fn from_duration(elapsed: Duration, period: Duration) -> Self {
+        let period = period.as_nanos();
+        if period == 0 {
+            return Self::ZERO;
+        }
+        let within = elapsed.as_nanos() % period;
+        Self::new(within as f64 / period as f64)
+    }
+
+    /// Return the phase of a cycle with the provided period which started
+    /// at the provided instant, as of now.
+    /// Instants in the future produce a phase of zero.
+    pub fn from_instant(since: Instant, period: Duration) -> Self {
+        Self::from_duration(Instant::now().saturating_duration_since(since), period)
+    }
+
+    /// Create a phase from the provided value without wrapping it.
+    /// The caller must ensure the value is in range; this is checked in
+    /// debug builds only.
+    pub fn new_unchecked(v: f64) -> Self {
+        debug_assert!((0.0..=1.0).contains(&v), "{} is out of range for Phase", v);
+        Self(v)
+    }
+
+    #[inline]
     fn wrap(&mut self) {
-        self.0 = self.0.rem_euclid(1.0);
+        // Like rem_euclid(1.0), without the call to fmod. Tiny negative
+        // inputs round up to exactly 1.0, which is still a valid phase.
+        self.0 -= self.0.floor();
+    }
+
+    /// Return the inner value, mapping 1.0 to 0.0.
+    /// A phase of 1.0 is the same point on the circle as 0.0.
+    fn canonical(&self) -> f64 {
+        if self.0 == 1.0 {
+            0.0
+        } else {
+            self.0
+        }
     }
 
     /// Return this phase as a UnipolarFloat.
@@ -286,9 +859,144 @@ impl Phase {
     }
 
     /// Return the inner phase.
+    #[inline]
     pub fn val(&self) -> f64 {
         self.0
     }
+
+    /// Return true if this phase lies in the interval running forward from
+    /// start to end, including start but excluding end.
+    /// If end is less than start, the interval crosses the wrap point.
+    /// If start and end are equal, the interval is empty.
+    pub fn is_between(&self, start: Phase, end: Phase) -> bool {
+        let v = self.canonical();
+        if start.0 <= end.0 {
+            v >= start.0 && v < end.0
+        } else {
+            v >= start.0 || v < end.0
+        }
+    }
+
+    /// Return the shortest signed offset which moves this phase to other.
+    /// The result is in the range [-0.5, 0.5); exactly opposite phases are
+    /// half a rotation backwards.
+    pub fn distance_to(&self, other: Phase) -> PhaseOffset {
+        let delta = (other.0 - self.0).rem_euclid(1.0);
+        PhaseOffset::new(if delta >= 0.5 { delta - 1.0 } else { delta })
+    }
+
+    /// Return the phase offset of fixture index out of count, for fanning an
+    /// effect across several fixtures.
+    /// A spread of 1 distributes the fixtures evenly around a full rotation;
+    /// a spread of 0 puts them all in phase.
+    /// A count of zero produces a zero offset.
+    pub fn offset_for(index: usize, count: usize, spread: UnipolarFloat) -> PhaseOffset {
+        if count == 0 {
+            return PhaseOffset::ZERO;
+        }
+        PhaseOffset::new(spread.0 * index as f64 / count as f64)
+    }
+
+    /// Return which of n equal divisions of the rotation this phase lies in.
+    /// The result is always in the range [0, n). A phase of 1.0 lies in
+    /// sector 0, since it is the same point on the circle as 0.0.
+    ///
+    /// Panics if n is zero.
+    pub fn sector(&self, n: usize) -> usize {
+        assert!(n > 0, "cannot divide a phase into zero sectors");
+        let v = self.canonical();
+        // Guard against rounding up into a nonexistent sector.
+        usize::min((v * n as f64) as usize, n - 1)
+    }
+
+    /// Return the position of this phase within its sector, if the rotation
+    /// is divided into n equal sectors.
+    ///
+    /// Panics if n is zero.
+    pub fn sector_phase(&self, n: usize) -> Phase {
+        let sector = self.sector(n);
+        let v = self.canonical();
+        let mut pos = v * n as f64 - sector as f64;
+        clamp(&mut pos, 0.0, 1.0);
+        Self(pos)
+    }
+
+    /// Map this phase onto an index into a collection of length len, as if
+    /// the collection were arranged in a circle. A phase of 1.0 wraps back
+    /// around to index 0.
+    ///
+    /// Panics if len is zero.
+    pub fn to_index(&self, len: usize) -> usize {
+        assert!(len > 0, "cannot index into an empty collection");
+        self.sector(len)
+    }
+
+    /// Select the element of slice at the index given by this phase, as if
+    /// the slice were arranged in a circle.
+    /// Return None if the slice is empty.
+    pub fn select_circular<'a, T>(&self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+        slice.get(self.to_index(slice.len()))
+    }
+
+    /// Round this phase to the nearest multiple of 1/divisions.
+    /// Phases that round up to a full rotation wrap back to 0.0.
+    ///
+    /// Panics if divisions is zero.
+    pub fn snap(&self, divisions: usize) -> Phase {
+        assert!(divisions > 0, "cannot snap a phase to zero divisions");
+        let n = divisions as f64;
+        Self::new((self.0 * n).round() / n)
+    }
+
+    /// Quantize this phase down to the start of its subdivision, if the
+    /// rotation is divided into the provided number of divisions, with every
+    /// other division skewed by swing.
+    /// Positive swing delays the odd divisions and negative swing pulls them
+    /// earlier, by up to half a division at full swing. Zero swing is the
+    /// same as quantizing to straight divisions.
+    ///
+    /// Panics if divisions is zero.
+    pub fn quantize_musical(&self, divisions: usize, swing: BipolarFloat) -> Phase {
+        let n = divisions as f64;
+        let skew = swing.0 * 0.5;
+        let odd = |k: usize| k % 2 == 1;
+        let start = |k: usize| (k as f64 + if odd(k) { skew } else { 0.0 }) / n;
+
+        let v = self.canonical();
+        let mut k = self.sector(divisions);
+        // The swung boundaries may put us in a neighboring division.
+        if odd(k) && v < start(k) {
+            k -= 1;
+        } else if !odd(k) && k + 1 < divisions && v >= start(k + 1) {
+            k += 1;
+        }
+        Self(start(k))
+    }
+
+    /// Return the phase of the nth harmonic of this phase, such that the
+    /// result completes n rotations for every rotation of self.
+    pub fn harmonic(&self, n: u32) -> Phase {
+        Self::new(self.0 * f64::from(n))
+    }
+
+    /// Reflect this phase around 0.5, reversing its direction of travel.
+    pub fn mirror(&self) -> Phase {
+        Self::new(1.0 - self.0)
+    }
+
+    /// Fold this phase into a triangle which rises from 0 to 1 over the
+    /// first half of the rotation and falls back to 0 over the second half.
+    pub fn fold(&self) -> UnipolarFloat {
+        UnipolarFloat::new(1.0 - (2.0 * self.0 - 1.0).abs())
+    }
+
+    /// Return this phase as an angle in radians, on the range [0, 2π].
+    pub fn as_angle(&self) -> Angle {
+        Angle::from(*self)
+    }
 }
 
 impl From<Phase> for f64 {
@@ -313,6 +1021,30 @@ impl Add<f64> for Phase {
     }
 }
 
+impl Add<BipolarFloat> for Phase {
+    type Output = Phase;
+    /// Offset this phase by a signed fraction of a rotation, and wrap.
+    fn add(self, rhs: BipolarFloat) -> Self::Output {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub<Phase> for Phase {
+    type Output = Phase;
+    /// Implement subtraction as subtract followed by wrap.
+    fn sub(self, rhs: Phase) -> Self::Output {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl Sub<f64> for Phase {
+    type Output = Phase;
+    /// Implement subtraction as subtract followed by wrap.
+    fn sub(self, rhs: f64) -> Self::Output {
+        Self::new(self.0 - rhs)
+    }
+}
+
 impl AddAssign<Phase> for Phase {
     fn add_assign(&mut self, rhs: Phase) {
         *self = *self + rhs;
@@ -325,6 +1057,13 @@ impl AddAssign<f64> for Phase {
     }
 }
 
+impl AddAssign<BipolarFloat> for Phase {
+    /// Offset this phase by a signed fraction of a rotation, and wrap.
+    fn add_assign(&mut self, rhs: BipolarFloat) {
+        *self = *self + rhs;
+    }
+}
+
 impl Mul<UnipolarFloat> for Phase {
     type Output = Phase;
     fn mul(self, v: UnipolarFloat) -> Self {
@@ -334,6 +1073,12 @@ impl Mul<UnipolarFloat> for Phase {
     }
 }
 
+impl MulAssign<UnipolarFloat> for Phase {
+    fn mul_assign(&mut self, rhs: UnipolarFloat) {
+        *self = *self * rhs;
+    }
+}
+
 impl Mul<f64> for Phase {
     type Output = Phase;
     fn mul(self, v: f64) -> Self {
@@ -341,6 +1086,14 @@ impl Mul<f64> for Phase {
     }
 }
 
+impl Mul<u32> for Phase {
+    type Output = Phase;
+    /// Multiply by an integer harmonic number, and wrap.
+    fn mul(self, n: u32) -> Self {
+        self.harmonic(n)
+    }
+}
+
 impl Div<UnipolarFloat> for Phase {
     type Output = Phase;
     /// Divide a phase by a unit float.
@@ -368,3 +1121,119 @@ impl<T: Into<f64> + Copy> PartialEq<T> for Phase {
         self.0.eq(&o)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pulse_width_saturates_on_overflow() {
+        let max = Duration::MAX;
+        assert_eq!(UnipolarFloat::ONE.to_pulse_width(max, max), max);
+        assert_eq!(BipolarFloat::ONE.to_pulse_width(max, max), max);
+        assert_eq!(
+            BipolarFloat::ONE.to_pulse_width(Duration::ZERO, Duration::ZERO),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn division_by_either_zero_saturates_by_sign_of_self() {
+        for zero in [0.0, -0.0] {
+            let mut u = UnipolarFloat::new(0.5);
+            u /= zero;
+            assert_eq!(u, UnipolarFloat::ONE);
+            let mut u = UnipolarFloat::ZERO;
+            u /= zero;
+            assert_eq!(u, UnipolarFloat::ZERO);
+
+            let mut b = BipolarFloat::new(0.5);
+            b /= zero;
+            assert_eq!(b, BipolarFloat::ONE);
+            let mut b = BipolarFloat::new(-0.5);
+            b /= zero;
+            assert_eq!(b, BipolarFloat::new(-1.0));
+            let mut b = BipolarFloat::ZERO;
+            b /= zero;
+            assert_eq!(b, BipolarFloat::ZERO);
+        }
+    }
+
+    fn quantize(phase: f64, divisions: usize, swing: f64) -> f64 {
+        Phase::new(phase)
+            .quantize_musical(divisions, BipolarFloat::new(swing))
+            .val()
+    }
+
+    #[test]
+    fn quantize_musical_without_swing_is_straight() {
+        assert_eq!(quantize(0.0, 4, 0.0), 0.0);
+        assert_eq!(quantize(0.3, 4, 0.0), 0.25);
+        assert_eq!(quantize(0.99, 4, 0.0), 0.75);
+        assert_eq!(quantize(1.0, 4, 0.0), 0.0);
+    }
+
+    #[test]
+    fn positive_swing_delays_odd_divisions() {
+        // The odd divisions start at 0.375 and 0.875.
+        assert_eq!(quantize(0.3, 4, 1.0), 0.0);
+        assert_eq!(quantize(0.375, 4, 1.0), 0.375);
+        assert_eq!(quantize(0.5, 4, 1.0), 0.5);
+        assert_eq!(quantize(0.85, 4, 1.0), 0.5);
+        assert_eq!(quantize(0.9, 4, 1.0), 0.875);
+    }
+
+    #[test]
+    fn negative_swing_pulls_odd_divisions_earlier() {
+        // The odd divisions start at 0.125 and 0.625.
+        assert_eq!(quantize(0.1, 4, -1.0), 0.0);
+        assert_eq!(quantize(0.2, 4, -1.0), 0.125);
+        assert_eq!(quantize(0.6, 4, -1.0), 0.5);
+        assert_eq!(quantize(0.63, 4, -1.0), 0.625);
+    }
+
+    #[test]
+    fn swing_with_an_odd_number_of_divisions() {
+        // The last division is even, so nothing follows it to move into.
+        assert_eq!(quantize(0.9, 3, -1.0), 2.0 / 3.0);
+        assert_eq!(quantize(0.2, 3, -1.0), 1.0 / 6.0);
+    }
+
+    fn between(phase: f64, start: f64, end: f64) -> bool {
+        Phase::new(phase).is_between(Phase::new(start), Phase::new(end))
+    }
+
+    #[test]
+    fn is_between_is_half_open() {
+        assert!(between(0.25, 0.25, 0.5));
+        assert!(between(0.4, 0.25, 0.5));
+        assert!(!between(0.5, 0.25, 0.5));
+        assert!(!between(0.1, 0.25, 0.5));
+        assert!(!between(0.25, 0.25, 0.25));
+    }
+
+    #[test]
+    fn is_between_across_the_wrap_point() {
+        assert!(between(0.9, 0.75, 0.25));
+        assert!(between(0.0, 0.75, 0.25));
+        assert!(between(1.0, 0.75, 0.25));
+        assert!(between(0.1, 0.75, 0.25));
+        assert!(!between(0.25, 0.75, 0.25));
+        assert!(!between(0.5, 0.75, 0.25));
+    }
+
+    #[test]
+    fn is_between_with_phase_one_endpoints() {
+        let half = Phase::new(0.5);
+        // An end of ONE runs to the end of the rotation.
+        assert!(Phase::new(0.9).is_between(half, Phase::ONE));
+        assert!(Phase::ZERO.is_between(Phase::ZERO, Phase::ONE));
+        assert!(!Phase::ZERO.is_between(half, Phase::ONE));
+        assert!(!Phase::ONE.is_between(half, Phase::ONE));
+        // ONE lies at the same point as ZERO, whether as start or phase.
+        assert!(Phase::ZERO.is_between(Phase::ONE, half));
+        assert!(Phase::ONE.is_between(Phase::ONE, half));
+        assert!(Phase::ONE.is_between(Phase::ZERO, half));
+        assert!(!half.is_between(Phase::ONE, half));
+    }
+}
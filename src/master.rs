@@ -0,0 +1,106 @@
+use crate::UnipolarFloat;
+
+/// A chain of master levels, such as grand master, group master, and
+/// fixture master, which scale a level in series.
+/// The combined factor is cached and only recomputed when a level changes.
+/// The chain also tracks whether any level has changed since the last time
+/// the dirty flag was cleared, so dependent channels can skip recomputing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasterChain {
+    levels: Vec<UnipolarFloat>,
+    combined: UnipolarFloat,
+    dirty: bool,
+}
+
+impl MasterChain {
+    /// Create a chain of n masters, all at full.
+    pub fn new(n: usize) -> Self {
+        Self::from_levels(vec![UnipolarFloat::ONE; n])
+    }
+
+    /// Create a chain from the provided levels.
+    pub fn from_levels(levels: Vec<UnipolarFloat>) -> Self {
+        let mut chain = Self {
+            levels,
+            combined: UnipolarFloat::ONE,
+            dirty: true,
+        };
+        chain.recompute();
+        chain
+    }
+
+    /// Return the number of masters in the chain.
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Return true if the chain has no masters; its combined factor is 1.
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Return the level of the master at index i.
+    ///
+    /// Panics if i is out of bounds.
+    pub fn level(&self, i: usize) -> UnipolarFloat {
+        self.levels[i]
+    }
+
+    /// Return all of the master levels, in order.
+    pub fn levels(&self) -> &[UnipolarFloat] {
+        &self.levels
+    }
+
+    /// Set the level of the master at index i.
+    /// The chain is only marked dirty if the level actually changed.
+    ///
+    /// Panics if i is out of bounds.
+    pub fn set(&mut self, i: usize, level: UnipolarFloat) {
+        if self.levels[i] == level {
+            return;
+        }
+        self.levels[i] = level;
+        self.recompute();
+    }
+
+    /// Return the product of every master level in the chain.
+    pub fn combined(&self) -> UnipolarFloat {
+        self.combined
+    }
+
+    /// Scale a level by the combined master factor.
+    pub fn apply(&self, level: UnipolarFloat) -> UnipolarFloat {
+        level * self.combined
+    }
+
+    /// Scale every level in the slice by the combined master factor.
+    pub fn apply_slice(&self, levels: &mut [UnipolarFloat]) {
+        for level in levels {
+            *level *= self.combined;
+        }
+    }
+
+    /// Return true if any level has changed since the last clear_dirty.
+    /// A newly-created chain is dirty.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Mark the chain as clean.
+    pub fn clear_dirty(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Return whether the chain is dirty, and mark it as clean.
+    pub fn take_dirty(&mut self) -> bool {
+        std::mem::replace(&mut self.dirty, false)
+    }
+
+    fn recompute(&mut self) {
+        self.combined = self
+            .levels
+            .iter()
+            .fold(UnipolarFloat::ONE, |product, level| product * *level);
+        self.dirty = true;
+    }
+}
@@ -0,0 +1,134 @@
+//! Functions for combining several values into one.
+//!
+//! Functions taking two slices pair their entries in order; excess entries
+//! in the longer slice are ignored.
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BipolarFloat, UnipolarFloat};
+
+/// Return the weighted average of values.
+/// The weights are normalized by their sum, so they need not sum to 1.
+/// If every weight is zero, the result is zero.
+pub fn blend(values: &[UnipolarFloat], weights: &[UnipolarFloat]) -> UnipolarFloat {
+    let (sum, total_weight) = values
+        .iter()
+        .zip(weights)
+        .fold((0.0, 0.0), |(sum, total), (v, w)| {
+            (sum + v.0 * w.0, total + w.0)
+        });
+    if total_weight == 0.0 {
+        return UnipolarFloat::ZERO;
+    }
+    // Guard against rounding pushing the average just out of range.
+    UnipolarFloat::new(sum / total_weight)
+}
+
+/// Merge two levels, highest takes precedence.
+pub fn htp(a: UnipolarFloat, b: UnipolarFloat) -> UnipolarFloat {
+    // f64::max cannot produce an out-of-range result here.
+    UnipolarFloat(f64::max(a.0, b.0))
+}
+
+/// Merge src into dest, highest takes precedence.
+pub fn htp_slice(dest: &mut [UnipolarFloat], src: &[UnipolarFloat]) {
+    for (d, s) in dest.iter_mut().zip(src) {
+        *d = htp(*d, *s);
+    }
+}
+
+/// A level tagged with a stamp for latest-takes-precedence merging.
+/// The stamp is typically the time the level was last set, but may be any
+/// ordered value such as a sequence number or priority.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ltp<S> {
+    pub value: UnipolarFloat,
+    pub stamp: S,
+}
+
+impl<S> Ltp<S> {
+    pub fn new(value: UnipolarFloat, stamp: S) -> Self {
+        Self { value, stamp }
+    }
+}
+
+/// Merge two stamped levels, latest (greatest stamp) takes precedence.
+/// If the stamps are equal, b wins.
+pub fn ltp<S: Ord>(a: Ltp<S>, b: Ltp<S>) -> Ltp<S> {
+    if a.stamp > b.stamp {
+        a
+    } else {
+        b
+    }
+}
+
+/// Merge src into dest, latest takes precedence.
+/// If the stamps are equal, the value from src wins.
+pub fn ltp_slice<S: Ord + Copy>(dest: &mut [Ltp<S>], src: &[Ltp<S>]) {
+    for (d, s) in dest.iter_mut().zip(src) {
+        *d = ltp(*d, *s);
+    }
+}
+
+/// Rescale levels in place so that they sum to 1, for use as weights.
+/// If the levels sum to zero, every level is set to an equal share.
+pub fn normalize_sum(levels: &mut [UnipolarFloat]) {
+    if levels.is_empty() {
+        return;
+    }
+    let sum: f64 = levels.iter().map(|v| v.0).sum();
+    if sum == 0.0 {
+        let share = UnipolarFloat::new(1.0 / levels.len() as f64);
+        levels.fill(share);
+        return;
+    }
+    for v in levels {
+        *v = UnipolarFloat::new(v.0 / sum);
+    }
+}
+
+/// Convert arbitrary scores into weights which sum to 1, with higher scores
+/// receiving exponentially more weight, writing the weights into out.
+/// Scores should be finite.
+pub fn softmax(scores: &[f64], out: &mut [UnipolarFloat]) {
+    let n = usize::min(scores.len(), out.len());
+    let scores = &scores[..n];
+    // Subtract the maximum so that exp cannot overflow.
+    let max = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let sum: f64 = scores.iter().map(|s| (s - max).exp()).sum();
+    for (o, s) in out.iter_mut().zip(scores) {
+        *o = UnipolarFloat::new((s - max).exp() / sum);
+    }
+}
+
+/// Scale levels in place so that the largest becomes 1.
+/// If every level is zero, the levels are left unchanged.
+pub fn normalize_peak(levels: &mut [UnipolarFloat]) {
+    let peak = levels.iter().map(|v| v.0).fold(0.0, f64::max);
+    if peak == 0.0 {
+        return;
+    }
+    for v in levels {
+        *v = UnipolarFloat::new(v.0 / peak);
+    }
+}
+
+macro_rules! impl_extrema_assign {
+    ($t:ident) => {
+        impl $t {
+            /// Replace self with other if other is greater.
+            pub fn max_assign(&mut self, other: Self) {
+                self.0 = f64::max(self.0, other.0);
+            }
+
+            /// Replace self with other if other is less.
+            pub fn min_assign(&mut self, other: Self) {
+                self.0 = f64::min(self.0, other.0);
+            }
+        }
+    };
+}
+
+impl_extrema_assign!(UnipolarFloat);
+impl_extrema_assign!(BipolarFloat);
@@ -0,0 +1,140 @@
+/// Define a domain-specific newtype wrapping a UnipolarFloat, such as
+/// `Intensity` or `Speed`, with the usual constructors, conversions,
+/// arithmetic, Display, and (with the serde feature) serde implementations.
+///
+/// ```
+/// use number::UnipolarFloat;
+///
+/// number::unipolar_newtype! {
+///     /// The intensity of a fixture.
+///     pub struct Intensity;
+/// }
+///
+/// let full = Intensity::new(1.5);
+/// assert_eq!(full, Intensity::ONE);
+/// let half = full * UnipolarFloat::new(0.5);
+/// assert_eq!(f64::from(half), 0.5);
+/// assert_eq!(half.to_string(), "0.5");
+/// ```
+#[macro_export]
+macro_rules! unipolar_newtype {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident;) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+        $vis struct $name($crate::UnipolarFloat);
+
+        impl $name {
+            pub const ZERO: Self = Self($crate::UnipolarFloat::ZERO);
+            pub const ONE: Self = Self($crate::UnipolarFloat::ONE);
+
+            /// Clamp the provided value to the unit range.
+            pub fn new(v: f64) -> Self {
+                Self($crate::UnipolarFloat::new(v))
+            }
+
+            /// Return the inner float value.
+            pub fn val(&self) -> f64 {
+                self.0.val()
+            }
+
+            /// Return the wrapped UnipolarFloat.
+            pub fn unipolar(&self) -> $crate::UnipolarFloat {
+                self.0
+            }
+
+            /// Return the negation of this value, mapping 1 to 0 and 0 to 1.
+            pub fn invert(&self) -> Self {
+                Self(self.0.invert())
+            }
+        }
+
+        impl ::std::convert::From<$crate::UnipolarFloat> for $name {
+            fn from(value: $crate::UnipolarFloat) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<$name> for $crate::UnipolarFloat {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::std::convert::From<$name> for f64 {
+            fn from(value: $name) -> Self {
+                value.0.val()
+            }
+        }
+
+        impl ::std::ops::Mul for $name {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self(self.0 * rhs.0)
+            }
+        }
+
+        impl ::std::ops::Mul<$crate::UnipolarFloat> for $name {
+            type Output = Self;
+            fn mul(self, rhs: $crate::UnipolarFloat) -> Self::Output {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl ::std::ops::Add for $name {
+            type Output = Self;
+            /// Add other to self and clamp.
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl ::std::ops::Sub for $name {
+            type Output = Self;
+            /// Subtract other from self and clamp.
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        $crate::__newtype_serde!($name);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($name:ident) => {
+        impl $crate::__serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                $crate::__serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: $crate::__serde::Deserializer<'de>,
+            {
+                <$crate::UnipolarFloat as $crate::__serde::Deserialize>::deserialize(deserializer)
+                    .map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($name:ident) => {};
+}
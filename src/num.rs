@@ -0,0 +1,119 @@
+//! num-traits implementations, for use in generic numeric code.
+use num_traits::{Bounded, FromPrimitive, One, ToPrimitive, Zero};
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+impl Zero for UnipolarFloat {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+impl Zero for BipolarFloat {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+impl Zero for Phase {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+impl One for UnipolarFloat {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl One for BipolarFloat {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl Bounded for UnipolarFloat {
+    fn min_value() -> Self {
+        Self::ZERO
+    }
+
+    fn max_value() -> Self {
+        Self::ONE
+    }
+}
+
+impl Bounded for BipolarFloat {
+    fn min_value() -> Self {
+        Self(-1.0)
+    }
+
+    fn max_value() -> Self {
+        Self::ONE
+    }
+}
+
+impl Bounded for Phase {
+    fn min_value() -> Self {
+        Self::ZERO
+    }
+
+    fn max_value() -> Self {
+        Self::ONE
+    }
+}
+
+macro_rules! impl_primitive {
+    ($t:ident, $min:expr, $max:expr) => {
+        impl ToPrimitive for $t {
+            fn to_i64(&self) -> Option<i64> {
+                self.0.to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.0.to_u64()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0)
+            }
+        }
+
+        impl FromPrimitive for $t {
+            /// Return None if the value lies outside of the range.
+            fn from_i64(n: i64) -> Option<Self> {
+                Self::from_f64(n as f64)
+            }
+
+            /// Return None if the value lies outside of the range.
+            fn from_u64(n: u64) -> Option<Self> {
+                Self::from_f64(n as f64)
+            }
+
+            /// Return None if the value lies outside of the range.
+            fn from_f64(n: f64) -> Option<Self> {
+                if ($min..=$max).contains(&n) {
+                    Some(Self(n))
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+impl_primitive!(UnipolarFloat, 0.0, 1.0);
+impl_primitive!(BipolarFloat, -1.0, 1.0);
+impl_primitive!(Phase, 0.0, 1.0);
@@ -0,0 +1,91 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[cfg(feature = "derive_more")]
+use derive_more::Display;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Phase;
+
+/// A signed difference between two phases, as a fraction of a rotation.
+/// Offsets are unbounded, so they can represent multiple rotations, but
+/// Phase::distance_to always produces an offset in the range [-0.5, 0.5).
+#[cfg_attr(feature = "derive_more", derive(Display))]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PhaseOffset(f64);
+
+impl PhaseOffset {
+    pub const ZERO: Self = Self(0.0);
+
+    pub fn new(v: f64) -> Self {
+        Self(v)
+    }
+
+    /// Return the inner float value.
+    pub fn val(&self) -> f64 {
+        self.0
+    }
+}
+
+impl From<PhaseOffset> for f64 {
+    fn from(value: PhaseOffset) -> Self {
+        value.0
+    }
+}
+
+impl Neg for PhaseOffset {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Add for PhaseOffset {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for PhaseOffset {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul<f64> for PhaseOffset {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Add<PhaseOffset> for Phase {
+    type Output = Phase;
+    /// Offset this phase, and wrap.
+    fn add(self, rhs: PhaseOffset) -> Self::Output {
+        Phase::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub<PhaseOffset> for Phase {
+    type Output = Phase;
+    /// Offset this phase backwards, and wrap.
+    fn sub(self, rhs: PhaseOffset) -> Self::Output {
+        Phase::new(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign<PhaseOffset> for Phase {
+    fn add_assign(&mut self, rhs: PhaseOffset) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<PhaseOffset> for Phase {
+    fn sub_assign(&mut self, rhs: PhaseOffset) {
+        *self = *self - rhs;
+    }
+}
@@ -0,0 +1,68 @@
+//! Conversions between the numeric types and OSC arguments.
+use std::{convert::TryFrom, error::Error, fmt};
+
+use rosc::OscType;
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+/// An OSC argument could not be converted into a numeric type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OscConversionError {
+    /// The name of the type we tried to convert into.
+    pub target: &'static str,
+    /// The argument that we could not convert.
+    pub arg: OscType,
+}
+
+impl fmt::Display for OscConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot convert OSC argument {:?} into {}; expected a float or double",
+            self.arg, self.target
+        )
+    }
+}
+
+impl Error for OscConversionError {}
+
+fn float_arg(arg: &OscType, target: &'static str) -> Result<f64, OscConversionError> {
+    match arg {
+        OscType::Float(v) => Ok(f64::from(*v)),
+        OscType::Double(v) => Ok(*v),
+        _ => Err(OscConversionError {
+            target,
+            arg: arg.clone(),
+        }),
+    }
+}
+
+macro_rules! impl_osc {
+    ($t:ident) => {
+        impl TryFrom<&OscType> for $t {
+            type Error = OscConversionError;
+            /// Convert a float or double argument, constraining it into range.
+            fn try_from(arg: &OscType) -> Result<Self, Self::Error> {
+                float_arg(arg, stringify!($t)).map(Self::new)
+            }
+        }
+
+        impl TryFrom<OscType> for $t {
+            type Error = OscConversionError;
+            /// Convert a float or double argument, constraining it into range.
+            fn try_from(arg: OscType) -> Result<Self, Self::Error> {
+                Self::try_from(&arg)
+            }
+        }
+
+        impl From<$t> for OscType {
+            fn from(value: $t) -> Self {
+                OscType::Float(value.val() as f32)
+            }
+        }
+    };
+}
+
+impl_osc!(UnipolarFloat);
+impl_osc!(BipolarFloat);
+impl_osc!(Phase);
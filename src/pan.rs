@@ -0,0 +1,93 @@
+//! Distributing a signal across several outputs.
+use std::f64::consts::FRAC_PI_4;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+/// How the gain of two channels varies as a signal is panned between them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PanLaw {
+    /// Both channels are at full gain in the center, and each fades out
+    /// linearly as the signal moves towards the other side.
+    Linear,
+    /// The -3 dB law: gains follow a quarter sine, so the summed power of
+    /// the two channels is constant.
+    #[default]
+    ConstantPower,
+    /// The -6 dB law: gains crossfade linearly, so the summed gain of the
+    /// two channels is constant.
+    ConstantGain,
+}
+
+/// Return the gains of the left and right channels when a signal is panned
+/// to position, from -1 (hard left) to 1 (hard right).
+pub fn pan(position: BipolarFloat, law: PanLaw) -> (UnipolarFloat, UnipolarFloat) {
+    let p = position.0;
+    let (left, right) = match law {
+        PanLaw::Linear => (f64::min(1.0 - p, 1.0), f64::min(1.0 + p, 1.0)),
+        PanLaw::ConstantPower => {
+            let angle = (p + 1.0) * FRAC_PI_4;
+            (angle.cos(), angle.sin())
+        }
+        PanLaw::ConstantGain => ((1.0 - p) / 2.0, (1.0 + p) / 2.0),
+    };
+    (UnipolarFloat::new(left), UnipolarFloat::new(right))
+}
+
+/// Encode a left and right pair as their mid (average) and side (half
+/// difference) components.
+/// Both components are halved, so they always stay in range.
+pub fn mid_side_encode(l: BipolarFloat, r: BipolarFloat) -> (BipolarFloat, BipolarFloat) {
+    (
+        BipolarFloat((l.0 + r.0) / 2.0),
+        BipolarFloat((l.0 - r.0) / 2.0),
+    )
+}
+
+/// Decode mid and side components back into a left and right pair.
+/// This exactly inverts mid_side_encode; components which did not come
+/// from an encoded pair may decode out of range, and are clamped.
+pub fn mid_side_decode(mid: BipolarFloat, side: BipolarFloat) -> (BipolarFloat, BipolarFloat) {
+    (
+        BipolarFloat::clamped(mid.0 + side.0),
+        BipolarFloat::clamped(mid.0 - side.0),
+    )
+}
+
+/// Return the gains of outputs evenly spaced around a ring, starting with
+/// output 0 at phase 0, when a signal is panned to position.
+/// Width is the fraction of the ring the signal spreads across; at zero
+/// width the signal pans between the two nearest outputs. Gains are
+/// normalized so that their summed power is constant.
+pub fn circular_pan(position: Phase, outputs: usize, width: UnipolarFloat) -> Vec<UnipolarFloat> {
+    let mut gains = vec![UnipolarFloat::ZERO; outputs];
+    circular_pan_into(position, width, &mut gains);
+    gains
+}
+
+/// Write the gains of outputs evenly spaced around a ring into out, as for
+/// circular_pan, with one output per entry.
+pub fn circular_pan_into(position: Phase, width: UnipolarFloat, out: &mut [UnipolarFloat]) {
+    let n = out.len();
+    if n == 0 {
+        return;
+    }
+    // Spread across at least the spacing between adjacent outputs.
+    let spread = f64::max(width.0 / 2.0, 1.0 / n as f64);
+    let mut power = 0.0;
+    for (k, gain) in out.iter_mut().enumerate() {
+        let output = Phase::new(k as f64 / n as f64);
+        let distance = position.distance_to(output).val().abs();
+        *gain = UnipolarFloat::clamped(1.0 - distance / spread);
+        power += gain.0 * gain.0;
+    }
+    // The nearest output is always within the spread, so power is positive.
+    let scale = power.sqrt().recip();
+    for gain in out {
+        // Guard against rounding just above 1.
+        *gain = UnipolarFloat::clamped(gain.0 * scale);
+    }
+}
@@ -0,0 +1,88 @@
+//! Parsing values from user-entered text.
+use std::{error::Error, f64::consts::TAU, fmt, str::FromStr};
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+/// Text could not be parsed as a numeric type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The name of the type we tried to parse.
+    pub target: &'static str,
+    /// The text that could not be parsed.
+    pub input: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot parse \"{}\" as {}", self.input, self.target)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse a number with an optional unit suffix, applying the scale factor
+/// associated with the first matching suffix. A bare number is unscaled.
+fn parse_with_units(
+    s: &str,
+    units: &[(&str, f64)],
+    target: &'static str,
+) -> Result<f64, ParseError> {
+    let trimmed = s.trim();
+    let (number, scale) = units
+        .iter()
+        .find_map(|(suffix, scale)| {
+            trimmed
+                .strip_suffix(suffix)
+                .map(|number| (number.trim_end(), *scale))
+        })
+        .unwrap_or((trimmed, 1.0));
+    match number.parse::<f64>() {
+        Ok(v) if v.is_finite() => Ok(v * scale),
+        _ => Err(ParseError {
+            target,
+            input: s.to_string(),
+        }),
+    }
+}
+
+const PERCENT: (&str, f64) = ("%", 0.01);
+
+impl FromStr for UnipolarFloat {
+    type Err = ParseError;
+    /// Parse a number such as "0.5", or a percentage such as "50%".
+    /// The result is clamped into range.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_with_units(s, &[PERCENT], "UnipolarFloat").map(Self::new)
+    }
+}
+
+impl FromStr for BipolarFloat {
+    type Err = ParseError;
+    /// Parse a signed number such as "-0.5" or "+0.25", or a percentage
+    /// such as "-50%". The result is clamped into range.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_with_units(s, &[PERCENT], "BipolarFloat").map(Self::new)
+    }
+}
+
+impl FromStr for Phase {
+    type Err = ParseError;
+    /// Parse a phase in turns such as "0.25" or "0.25turn", in degrees such
+    /// as "90deg" or "90°", or in radians such as "1.57rad".
+    /// The result is wrapped into range.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_with_units(
+            s,
+            &[
+                ("turns", 1.0),
+                ("turn", 1.0),
+                ("deg", 1.0 / 360.0),
+                ("°", 1.0 / 360.0),
+                ("rad", 1.0 / TAU),
+                PERCENT,
+            ],
+            "Phase",
+        )
+        .map(Self::new)
+    }
+}
@@ -0,0 +1,112 @@
+//! Selectable strategies for handling out-of-range input.
+use std::{error::Error, fmt};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{clamp, BipolarFloat, Phase, UnipolarFloat};
+
+/// How to bring an out-of-range value into range.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RangePolicy {
+    /// Saturate at the nearest end of the range.
+    Clamp,
+    /// Wrap around, as if the range were circular.
+    Wrap,
+    /// Reflect back into the range off of each end.
+    Fold,
+    /// Reject the value.
+    Error,
+}
+
+/// A value lay outside of the range of the type it was converted into.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RangeError {
+    /// The out-of-range input.
+    pub value: f64,
+    /// The lower bound of the range.
+    pub min: f64,
+    /// The upper bound of the range.
+    pub max: f64,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is outside of the range [{}, {}]",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+impl Error for RangeError {}
+
+fn clamped(mut v: f64, min: f64, max: f64) -> f64 {
+    clamp(&mut v, min, max);
+    v
+}
+
+/// Wrap v into [min, max). Non-finite values are clamped.
+fn wrapped(v: f64, min: f64, max: f64) -> f64 {
+    clamped(min + (v - min).rem_euclid(max - min), min, max)
+}
+
+/// Reflect v into [min, max]. Non-finite values are clamped.
+fn folded(v: f64, min: f64, max: f64) -> f64 {
+    let span = max - min;
+    let x = (v - min).rem_euclid(2.0 * span);
+    let reflected = if x > span { 2.0 * span - x } else { x };
+    clamped(min + reflected, min, max)
+}
+
+fn checked(v: f64, min: f64, max: f64) -> Result<f64, RangeError> {
+    if v >= min && v <= max {
+        Ok(v)
+    } else {
+        Err(RangeError { value: v, min, max })
+    }
+}
+
+macro_rules! impl_policy {
+    ($t:ident, $min:expr, $max:expr) => {
+        impl $t {
+            /// Bring the provided value into range using the provided policy.
+            /// Only RangePolicy::Error can fail.
+            pub fn with_policy(v: f64, policy: RangePolicy) -> Result<Self, RangeError> {
+                Ok(match policy {
+                    RangePolicy::Clamp => Self::clamped(v),
+                    RangePolicy::Wrap => Self::wrapped(v),
+                    RangePolicy::Fold => Self::folded(v),
+                    RangePolicy::Error => return Self::try_new(v),
+                })
+            }
+
+            /// Clamp the provided value into range.
+            pub fn clamped(v: f64) -> Self {
+                Self(clamped(v, $min, $max))
+            }
+
+            /// Wrap the provided value into range, as if the range were
+            /// circular. The upper end of the range wraps to the lower end.
+            pub fn wrapped(v: f64) -> Self {
+                Self(wrapped(v, $min, $max))
+            }
+
+            /// Reflect the provided value back into range off of each end.
+            pub fn folded(v: f64) -> Self {
+                Self(folded(v, $min, $max))
+            }
+
+            /// Return an error if the provided value is out of range or NaN.
+            pub fn try_new(v: f64) -> Result<Self, RangeError> {
+                checked(v, $min, $max).map(Self)
+            }
+        }
+    };
+}
+
+impl_policy!(UnipolarFloat, 0.0, 1.0);
+impl_policy!(BipolarFloat, -1.0, 1.0);
+impl_policy!(Phase, 0.0, 1.0);
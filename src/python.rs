@@ -0,0 +1,52 @@
+//! Python conversions, validating floats into range at the boundary.
+use std::convert::Infallible;
+
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyFloat};
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+impl FromPyObject<'_, '_> for UnipolarFloat {
+    type Error = PyErr;
+    /// Raise ValueError if the value is outside of [0.0, 1.0].
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        Self::try_new(obj.extract()?).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+impl FromPyObject<'_, '_> for BipolarFloat {
+    type Error = PyErr;
+    /// Raise ValueError if the value is outside of [-1.0, 1.0].
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        Self::try_new(obj.extract()?).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+}
+
+impl FromPyObject<'_, '_> for Phase {
+    type Error = PyErr;
+    /// Wrap the value into range; raise ValueError if it is not finite.
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        let v: f64 = obj.extract()?;
+        if !v.is_finite() {
+            return Err(PyValueError::new_err(format!("{} is not a valid phase", v)));
+        }
+        Ok(Self::new(v))
+    }
+}
+
+macro_rules! impl_into_py {
+    ($t:ident) => {
+        impl<'py> IntoPyObject<'py> for $t {
+            type Target = PyFloat;
+            type Output = Bound<'py, PyFloat>;
+            type Error = Infallible;
+
+            fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+                Ok(PyFloat::new(py, self.0))
+            }
+        }
+    };
+}
+
+impl_into_py!(UnipolarFloat);
+impl_into_py!(BipolarFloat);
+impl_into_py!(Phase);
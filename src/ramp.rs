@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::UnipolarFloat;
+
+/// What a Ramp does once it reaches the top.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RampMode {
+    /// Drop back to 0 and stop.
+    #[default]
+    OneShot,
+    /// Stay at 1 until triggered again or reset.
+    Hold,
+    /// Drop back to 0 and rise again, until reset.
+    Loop,
+}
+
+/// A one-shot control signal which rises linearly from 0 to 1 over a
+/// configured duration each time it is triggered.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ramp {
+    duration: Duration,
+    mode: RampMode,
+    /// Progress through the current rise, or None if idle.
+    progress: Option<f64>,
+}
+
+impl Ramp {
+    pub fn new(duration: Duration, mode: RampMode) -> Self {
+        Self {
+            duration,
+            mode,
+            progress: None,
+        }
+    }
+
+    /// Start rising from 0, restarting if already running.
+    pub fn trigger(&mut self) {
+        self.progress = Some(0.0);
+    }
+
+    /// Stop the ramp and return the output to 0.
+    pub fn reset(&mut self) {
+        self.progress = None;
+    }
+
+    /// Return true if the ramp is rising, holding, or looping.
+    pub fn is_active(&self) -> bool {
+        self.progress.is_some()
+    }
+
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    pub fn set_mode(&mut self, mode: RampMode) {
+        self.mode = mode;
+    }
+
+    /// Return the current output level.
+    pub fn value(&self) -> UnipolarFloat {
+        match self.progress {
+            Some(p) => UnipolarFloat::new(p),
+            None => UnipolarFloat::ZERO,
+        }
+    }
+
+    /// Advance the ramp by dt and return the new output level.
+    pub fn update(&mut self, dt: Duration) -> UnipolarFloat {
+        if let Some(progress) = self.progress {
+            let next = if self.duration.is_zero() {
+                1.0
+            } else {
+                progress + dt.as_secs_f64() / self.duration.as_secs_f64()
+            };
+            self.progress = if next < 1.0 {
+                Some(next)
+            } else {
+                match self.mode {
+                    RampMode::OneShot => None,
+                    RampMode::Hold => Some(1.0),
+                    // Carry any overshoot into the next cycle.
+                    RampMode::Loop => Some(next.fract()),
+                }
+            };
+        }
+        self.value()
+    }
+}
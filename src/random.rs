@@ -0,0 +1,72 @@
+//! Shaped random sampling of values.
+use rand::Rng;
+use rand_distr::{Beta, BetaError, Distribution, Normal, NormalError, Triangular};
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+/// Give up on rejection sampling after this many draws.
+const MAX_REJECTIONS: usize = 1000;
+
+impl UnipolarFloat {
+    /// Draw a value from a Beta distribution with the provided shape
+    /// parameters. Shapes below 1 favor the ends of the range and shapes
+    /// above 1 favor the middle.
+    /// Return an error if either shape is not positive and finite.
+    pub fn sample_beta<R: Rng + ?Sized>(
+        alpha: f64,
+        beta: f64,
+        rng: &mut R,
+    ) -> Result<Self, BetaError> {
+        let dist = Beta::new(alpha, beta)?;
+        Ok(Self::new(dist.sample(rng)))
+    }
+
+    /// Draw a value from a triangular distribution over the unit range,
+    /// peaking at mode.
+    pub fn sample_triangular<R: Rng + ?Sized>(mode: UnipolarFloat, rng: &mut R) -> Self {
+        // The mode is always within the unit range, so this cannot fail.
+        let dist = Triangular::new(0.0, 1.0, mode.0).expect("invalid triangular distribution");
+        Self::new(dist.sample(rng))
+    }
+}
+
+impl BipolarFloat {
+    /// Draw a value from a normal distribution truncated to the bipolar
+    /// range, by redrawing samples which fall outside of it.
+    /// If the distribution lies almost entirely outside of the range, fall
+    /// back to drawing uniformly, which is what the truncated distribution
+    /// approaches.
+    /// Return an error if std_dev is negative or not finite.
+    pub fn sample_truncated_normal<R: Rng + ?Sized>(
+        mean: BipolarFloat,
+        std_dev: f64,
+        rng: &mut R,
+    ) -> Result<Self, NormalError> {
+        let dist = Normal::new(mean.0, std_dev)?;
+        for _ in 0..MAX_REJECTIONS {
+            let v = dist.sample(rng);
+            if (-1.0..=1.0).contains(&v) {
+                return Ok(Self(v));
+            }
+        }
+        Ok(Self(rng.random_range(-1.0..=1.0)))
+    }
+}
+
+macro_rules! impl_jitter {
+    ($t:ident, $restore:literal) => {
+        impl $t {
+            #[doc = concat!("Offset this value by uniform random noise of up to amount in either direction, then ", $restore, " the result.")]
+            pub fn jitter<R: Rng + ?Sized>(&self, amount: UnipolarFloat, rng: &mut R) -> Self {
+                if amount.0 == 0.0 {
+                    return *self;
+                }
+                Self::new(self.0 + rng.random_range(-amount.0..=amount.0))
+            }
+        }
+    };
+}
+
+impl_jitter!(UnipolarFloat, "clamp");
+impl_jitter!(BipolarFloat, "clamp");
+impl_jitter!(Phase, "wrap");
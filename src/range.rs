@@ -0,0 +1,67 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::UnipolarFloat;
+
+/// A sub-interval of the unit range.
+/// min may be greater than max, in which case the range runs in reverse;
+/// lerp will interpolate from min to max regardless of their ordering.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnipolarRange {
+    pub min: UnipolarFloat,
+    pub max: UnipolarFloat,
+}
+
+impl UnipolarRange {
+    /// The entire unit range.
+    pub const FULL: Self = Self {
+        min: UnipolarFloat::ZERO,
+        max: UnipolarFloat::ONE,
+    };
+
+    pub fn new(min: UnipolarFloat, max: UnipolarFloat) -> Self {
+        Self { min, max }
+    }
+
+    /// Return the lower and upper bounds of this range, in order.
+    fn bounds(&self) -> (UnipolarFloat, UnipolarFloat) {
+        if self.min <= self.max {
+            (self.min, self.max)
+        } else {
+            (self.max, self.min)
+        }
+    }
+
+    /// Return true if the value lies inside this range, inclusive of the ends.
+    pub fn contains(&self, v: UnipolarFloat) -> bool {
+        let (lo, hi) = self.bounds();
+        v >= lo && v <= hi
+    }
+
+    /// Clamp the value into this range.
+    pub fn clamp_into(&self, v: UnipolarFloat) -> UnipolarFloat {
+        let (lo, hi) = self.bounds();
+        UnipolarFloat(f64::min(f64::max(v.0, lo.0), hi.0))
+    }
+
+    /// Map a unit value onto this range.
+    /// 0 -> min, 1 -> max
+    pub fn lerp(&self, t: UnipolarFloat) -> UnipolarFloat {
+        UnipolarFloat::new(self.min.0 + (self.max.0 - self.min.0) * t.0)
+    }
+
+    /// Return the mirror image of this range, mapping each end v to 1 - v.
+    pub fn invert(&self) -> Self {
+        Self {
+            min: self.min.invert(),
+            max: self.max.invert(),
+        }
+    }
+}
+
+impl Default for UnipolarRange {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
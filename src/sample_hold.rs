@@ -0,0 +1,72 @@
+use crate::{Phase, UnipolarFloat};
+
+/// A stepped modulation source which latches a new value each time a
+/// driving phase crosses into a new subdivision of its rotation.
+///
+/// With one division, a new value is latched each time the phase wraps.
+/// Values are drawn from a small built-in pseudo-random generator, or may be
+/// provided by the caller with update_with.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SampleHold {
+    divisions: usize,
+    sector: Option<usize>,
+    value: UnipolarFloat,
+    rng: u64,
+}
+
+impl SampleHold {
+    /// Create a sample and hold which latches a new value divisions times
+    /// per rotation, drawing random values from the provided seed.
+    ///
+    /// Panics if divisions is zero.
+    pub fn new(divisions: usize, seed: u64) -> Self {
+        assert!(divisions > 0, "cannot divide a phase into zero sectors");
+        Self {
+            divisions,
+            sector: None,
+            value: UnipolarFloat::ZERO,
+            rng: seed,
+        }
+    }
+
+    /// Return the currently held value.
+    pub fn value(&self) -> UnipolarFloat {
+        self.value
+    }
+
+    /// Advance to the provided phase, latching a new random value if it
+    /// crossed a subdivision boundary, and return the held value.
+    /// The first update always latches a value.
+    pub fn update(&mut self, phase: Phase) -> UnipolarFloat {
+        let mut rng = self.rng;
+        let value = self.update_with(phase, || next_unipolar(&mut rng));
+        self.rng = rng;
+        value
+    }
+
+    /// Advance to the provided phase, latching the value produced by sample
+    /// if it crossed a subdivision boundary, and return the held value.
+    /// The first update always latches a value.
+    pub fn update_with<F>(&mut self, phase: Phase, sample: F) -> UnipolarFloat
+    where
+        F: FnOnce() -> UnipolarFloat,
+    {
+        let sector = phase.sector(self.divisions);
+        if self.sector != Some(sector) {
+            self.sector = Some(sector);
+            self.value = sample();
+        }
+        self.value
+    }
+}
+
+/// Draw a uniform value in [0, 1) using the splitmix64 generator.
+fn next_unipolar(state: &mut u64) -> UnipolarFloat {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    // Use the top 53 bits to fill the mantissa exactly.
+    UnipolarFloat((z >> 11) as f64 / (1u64 << 53) as f64)
+}
@@ -0,0 +1,47 @@
+//! JSON schema implementations, describing each type as a bounded number.
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+macro_rules! impl_json_schema {
+    ($t:ident, $min:expr, $max:expr, $desc:expr) => {
+        impl JsonSchema for $t {
+            fn inline_schema() -> bool {
+                true
+            }
+
+            fn schema_name() -> Cow<'static, str> {
+                stringify!($t).into()
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                concat!(module_path!(), "::", stringify!($t)).into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "number",
+                    "minimum": $min,
+                    "maximum": $max,
+                    "description": $desc,
+                })
+            }
+        }
+    };
+}
+
+impl_json_schema!(UnipolarFloat, 0.0, 1.0, "A number in the unit range.");
+impl_json_schema!(
+    BipolarFloat,
+    -1.0,
+    1.0,
+    "A number in the bipolar unit range."
+);
+impl_json_schema!(
+    Phase,
+    0.0,
+    1.0,
+    "A unit angular phase; values outside the range wrap."
+);
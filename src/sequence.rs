@@ -0,0 +1,113 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Interpolate, Phase, UnipolarFloat};
+
+/// A looping sequence of levels, evaluated by a driving phase which passes
+/// through every step once per rotation.
+/// Glide is the fraction of each step spent sliding into the next one;
+/// zero glide produces stepped output and full glide interpolates over the
+/// whole step. The last step glides back into the first.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StepSequence {
+    steps: Vec<UnipolarFloat>,
+    glide: UnipolarFloat,
+}
+
+impl StepSequence {
+    pub fn new(steps: Vec<UnipolarFloat>, glide: UnipolarFloat) -> Self {
+        Self { steps, glide }
+    }
+
+    pub fn steps(&self) -> &[UnipolarFloat] {
+        &self.steps
+    }
+
+    pub fn steps_mut(&mut self) -> &mut [UnipolarFloat] {
+        &mut self.steps
+    }
+
+    pub fn glide(&self) -> UnipolarFloat {
+        self.glide
+    }
+
+    pub fn set_glide(&mut self, glide: UnipolarFloat) {
+        self.glide = glide;
+    }
+
+    /// Return the output level at the provided phase.
+    /// An empty sequence always produces zero.
+    pub fn value(&self, phase: Phase) -> UnipolarFloat {
+        let n = self.steps.len();
+        if n == 0 {
+            return UnipolarFloat::ZERO;
+        }
+        let i = phase.sector(n);
+        let current = self.steps[i];
+        let glide = self.glide.0;
+        let hold = 1.0 - glide;
+        let pos = phase.sector_phase(n).0;
+        if glide == 0.0 || pos <= hold {
+            return current;
+        }
+        let t = UnipolarFloat::new((pos - hold) / glide);
+        current.interp(self.steps[(i + 1) % n], t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(steps: &[f64], glide: f64) -> StepSequence {
+        StepSequence::new(
+            steps.iter().copied().map(UnipolarFloat::new).collect(),
+            UnipolarFloat::new(glide),
+        )
+    }
+
+    fn value(sequence: &StepSequence, phase: f64) -> f64 {
+        sequence.value(Phase::new(phase)).val()
+    }
+
+    #[test]
+    fn zero_glide_is_stepped() {
+        let s = sequence(&[0.0, 1.0, 0.5, 0.25], 0.0);
+        assert_eq!(value(&s, 0.0), 0.0);
+        assert_eq!(value(&s, 0.24), 0.0);
+        assert_eq!(value(&s, 0.25), 1.0);
+        assert_eq!(value(&s, 0.6), 0.5);
+        assert_eq!(value(&s, 0.99), 0.25);
+    }
+
+    #[test]
+    fn full_glide_interpolates_over_the_whole_step() {
+        let s = sequence(&[0.0, 1.0, 0.5, 0.25], 1.0);
+        assert_eq!(value(&s, 0.0), 0.0);
+        assert_eq!(value(&s, 0.125), 0.5);
+        assert_eq!(value(&s, 0.25), 1.0);
+        assert_eq!(value(&s, 0.625), 0.375);
+    }
+
+    #[test]
+    fn partial_glide_holds_then_slides() {
+        let s = sequence(&[0.0, 1.0], 0.5);
+        assert_eq!(value(&s, 0.125), 0.0);
+        assert_eq!(value(&s, 0.375), 0.5);
+    }
+
+    #[test]
+    fn last_step_glides_back_into_the_first() {
+        let s = sequence(&[0.0, 1.0], 1.0);
+        assert_eq!(value(&s, 0.75), 0.5);
+        assert_eq!(value(&s, 0.875), 0.25);
+    }
+
+    #[test]
+    fn empty_sequence_is_zero() {
+        let s = sequence(&[], 0.5);
+        assert_eq!(value(&s, 0.0), 0.0);
+        assert_eq!(value(&s, 0.5), 0.0);
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{interpolate::hermite_f64, Phase, UnipolarFloat, UnitValue};
+
+/// A smooth curve passing through a sequence of control points, such as a
+/// custom modulation shape drawn from a handful of points.
+///
+/// The curve is a Catmull-Rom spline, which passes through every point. An
+/// open spline runs from the first point to the last; a closed spline loops
+/// from the last point back around to the first. The curve may overshoot
+/// between points; its value is clamped back into range.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Spline<T> {
+    points: Vec<T>,
+    closed: bool,
+}
+
+impl<T: UnitValue> Spline<T> {
+    /// Create an open spline through the provided points.
+    pub fn new(points: Vec<T>) -> Self {
+        Self {
+            points,
+            closed: false,
+        }
+    }
+
+    /// Create a closed spline which loops through the provided points.
+    pub fn closed(points: Vec<T>) -> Self {
+        Self {
+            points,
+            closed: true,
+        }
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    pub fn points_mut(&mut self) -> &mut [T] {
+        &mut self.points
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Return the value of the curve at the provided position along it.
+    /// A closed spline reaches its first point again at 1.0.
+    /// A spline without any points always produces zero.
+    pub fn value(&self, position: UnipolarFloat) -> T {
+        if self.closed {
+            return self.value_at_phase(position.as_phase());
+        }
+        let n = self.points.len();
+        if n < 2 {
+            return self.points.first().copied().unwrap_or(T::ZERO);
+        }
+        let s = position.val() * (n - 1) as f64;
+        let i = usize::min(s as usize, n - 2);
+        self.segment(i, s - i as f64, false)
+    }
+
+    /// Return the value of the curve at the provided phase, as if it were
+    /// closed. A phase of 1.0 is the same as 0.0.
+    /// A spline without any points always produces zero.
+    pub fn value_at_phase(&self, phase: Phase) -> T {
+        let n = self.points.len();
+        if n < 2 {
+            return self.points.first().copied().unwrap_or(T::ZERO);
+        }
+        self.segment(phase.sector(n), phase.sector_phase(n).val(), true)
+    }
+
+    /// Evaluate the curve at position t between points i and i + 1.
+    /// Points past either end wrap around if looped, or else repeat the end
+    /// points.
+    fn segment(&self, i: usize, t: f64, looped: bool) -> T {
+        let n = self.points.len() as isize;
+        let point = |k: isize| {
+            let k = if looped {
+                k.rem_euclid(n)
+            } else {
+                k.clamp(0, n - 1)
+            };
+            self.points[k as usize].val()
+        };
+        let i = i as isize;
+        let (p1, p2) = (point(i), point(i + 1));
+        let m1 = (p2 - point(i - 1)) / 2.0;
+        let m2 = (point(i + 2) - p1) / 2.0;
+        let v = hermite_f64(p1, p2, m1, m2, t);
+        if T::WRAPS {
+            UnitValue::new(v)
+        } else {
+            UnitValue::new(v.clamp(T::MIN.val(), T::MAX.val()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BipolarFloat;
+
+    fn levels(values: &[f64]) -> Vec<UnipolarFloat> {
+        values.iter().copied().map(UnipolarFloat::new).collect()
+    }
+
+    fn at(spline: &Spline<UnipolarFloat>, position: f64) -> f64 {
+        spline.value(UnipolarFloat::new(position)).val()
+    }
+
+    #[test]
+    fn open_spline_passes_through_every_point() {
+        let spline = Spline::new(levels(&[0.0, 1.0, 0.5]));
+        assert_eq!(at(&spline, 0.0), 0.0);
+        assert_eq!(at(&spline, 0.5), 1.0);
+        assert_eq!(at(&spline, 1.0), 0.5);
+    }
+
+    #[test]
+    fn closed_spline_loops_back_to_the_first_point() {
+        let spline = Spline::closed(levels(&[0.0, 1.0, 0.5, 0.25]));
+        assert_eq!(at(&spline, 0.0), 0.0);
+        assert_eq!(at(&spline, 0.25), 1.0);
+        assert_eq!(at(&spline, 0.75), 0.25);
+        assert_eq!(at(&spline, 1.0), 0.0);
+        assert_eq!(spline.value_at_phase(Phase::ONE).val(), 0.0);
+    }
+
+    #[test]
+    fn constant_points_give_a_constant_curve() {
+        let spline = Spline::new(levels(&[0.5; 4]));
+        for step in 0..=10 {
+            assert_eq!(at(&spline, f64::from(step) / 10.0), 0.5);
+        }
+    }
+
+    #[test]
+    fn overshoot_is_clamped_into_range() {
+        // Between the two middle points the curve rises above 1.
+        let spline = Spline::new(levels(&[0.0, 1.0, 1.0, 0.0]));
+        assert_eq!(at(&spline, 0.5), 1.0);
+        let spline = Spline::new(vec![
+            BipolarFloat::ZERO,
+            BipolarFloat::new(-1.0),
+            BipolarFloat::new(-1.0),
+            BipolarFloat::ZERO,
+        ]);
+        assert_eq!(spline.value(UnipolarFloat::new(0.5)).val(), -1.0);
+    }
+
+    #[test]
+    fn splines_with_fewer_than_two_points() {
+        assert_eq!(at(&Spline::new(Vec::new()), 0.5), 0.0);
+        assert_eq!(at(&Spline::new(levels(&[0.25])), 0.5), 0.25);
+        assert_eq!(at(&Spline::closed(levels(&[0.25])), 0.5), 0.25);
+    }
+}
@@ -0,0 +1,104 @@
+//! Database conversions, storing values as double precision floats.
+//! Values read from the database are validated, and out-of-range values
+//! are reported as decode errors rather than silently clamped.
+#[cfg(feature = "sqlx")]
+mod sqlx_impl {
+    use sqlx::{
+        database::Database,
+        decode::Decode,
+        encode::{Encode, IsNull},
+        error::BoxDynError,
+        types::Type,
+    };
+
+    use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+    macro_rules! impl_sqlx {
+        ($t:ident) => {
+            impl<DB: Database> Type<DB> for $t
+            where
+                f64: Type<DB>,
+            {
+                fn type_info() -> DB::TypeInfo {
+                    <f64 as Type<DB>>::type_info()
+                }
+
+                fn compatible(ty: &DB::TypeInfo) -> bool {
+                    <f64 as Type<DB>>::compatible(ty)
+                }
+            }
+
+            impl<'q, DB: Database> Encode<'q, DB> for $t
+            where
+                f64: Encode<'q, DB>,
+            {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+                ) -> Result<IsNull, BoxDynError> {
+                    self.0.encode_by_ref(buf)
+                }
+            }
+
+            impl<'r, DB: Database> Decode<'r, DB> for $t
+            where
+                f64: Decode<'r, DB>,
+            {
+                fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                    Ok(Self::try_new(f64::decode(value)?)?)
+                }
+            }
+        };
+    }
+
+    impl_sqlx!(UnipolarFloat);
+    impl_sqlx!(BipolarFloat);
+    impl_sqlx!(Phase);
+}
+
+#[cfg(feature = "postgres")]
+mod postgres_impl {
+    use std::error::Error;
+
+    use bytes::BytesMut;
+    use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+    use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+    macro_rules! impl_postgres {
+        ($t:ident) => {
+            impl<'a> FromSql<'a> for $t {
+                fn from_sql(
+                    ty: &Type,
+                    raw: &'a [u8],
+                ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                    Ok(Self::try_new(f64::from_sql(ty, raw)?)?)
+                }
+
+                fn accepts(ty: &Type) -> bool {
+                    <f64 as FromSql>::accepts(ty)
+                }
+            }
+
+            impl ToSql for $t {
+                fn to_sql(
+                    &self,
+                    ty: &Type,
+                    out: &mut BytesMut,
+                ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+                    self.0.to_sql(ty, out)
+                }
+
+                fn accepts(ty: &Type) -> bool {
+                    <f64 as ToSql>::accepts(ty)
+                }
+
+                to_sql_checked!();
+            }
+        };
+    }
+
+    impl_postgres!(UnipolarFloat);
+    impl_postgres!(BipolarFloat);
+    impl_postgres!(Phase);
+}
@@ -0,0 +1,214 @@
+//! Statistics over collections and streams of values.
+use crate::{BipolarFloat, Interpolate, Phase, UnipolarFloat, UnitValue};
+
+impl UnipolarFloat {
+    /// Return which of n equal-width buckets spanning the range this value
+    /// falls into. 1.0 falls into the last bucket.
+    ///
+    /// Panics if n is zero.
+    pub fn bucket(&self, n: usize) -> usize {
+        self.to_index(n)
+    }
+}
+
+impl BipolarFloat {
+    /// Return which of n equal-width buckets spanning the range this value
+    /// falls into. 1.0 falls into the last bucket.
+    ///
+    /// Panics if n is zero.
+    pub fn bucket(&self, n: usize) -> usize {
+        self.rescale_as_unipolar().to_index(n)
+    }
+}
+
+impl Phase {
+    /// Return which of n equal-width buckets spanning the rotation this
+    /// phase falls into. 1.0 falls into the first bucket.
+    ///
+    /// Panics if n is zero.
+    pub fn bucket(&self, n: usize) -> usize {
+        self.sector(n)
+    }
+}
+
+/// Count how many values fall into each of the provided number of
+/// equal-width buckets spanning the unit range.
+/// Return an empty histogram if there are no buckets.
+pub fn histogram<I>(values: I, buckets: usize) -> Vec<usize>
+where
+    I: IntoIterator<Item = UnipolarFloat>,
+{
+    let mut counts = vec![0; buckets];
+    if buckets == 0 {
+        return counts;
+    }
+    for v in values {
+        counts[v.bucket(buckets)] += 1;
+    }
+    counts
+}
+
+/// Return the qth quantile of values, linearly interpolating between the
+/// closest ranks; q = 0 is the minimum and q = 1 is the maximum.
+/// Runs in linear time on average, without sorting the values.
+/// An empty slice produces zero.
+pub fn percentile(values: &[UnipolarFloat], q: UnipolarFloat) -> UnipolarFloat {
+    if values.is_empty() {
+        return UnipolarFloat::ZERO;
+    }
+    let mut values = values.to_vec();
+    let rank = q.0 * (values.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let (_, &mut below, above) = values.select_nth_unstable_by(lo, |a, b| a.0.total_cmp(&b.0));
+    if above.is_empty() {
+        return below;
+    }
+    // The next rank up is the smallest of the values above.
+    let next = above.iter().map(|v| v.0).fold(f64::INFINITY, f64::min);
+    below.interp(UnipolarFloat(next), UnipolarFloat::new(rank - lo as f64))
+}
+
+/// Return the median of values, or zero if there are none.
+pub fn median(values: &[UnipolarFloat]) -> UnipolarFloat {
+    percentile(values, UnipolarFloat(0.5))
+}
+
+/// A running summary of a stream of values, updated in constant time and
+/// space per value, such as for auto-calibrating an input.
+/// The mean and variance are computed linearly, so they are not meaningful
+/// for phases.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stats<T> {
+    count: u64,
+    min: T,
+    max: T,
+    mean: f64,
+    /// The sum of squared differences from the mean, per Welford's method.
+    m2: f64,
+}
+
+impl<T: UnitValue> Stats<T> {
+    /// Create an accumulator which has not seen any values.
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            min: T::MAX,
+            max: T::MIN,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Incorporate a value into the summary.
+    pub fn push(&mut self, value: T) {
+        let v = value.val();
+        if v < self.min.val() {
+            self.min = value;
+        }
+        if v > self.max.val() {
+            self.max = value;
+        }
+        self.count += 1;
+        let delta = v - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (v - self.mean);
+    }
+
+    /// Forget every value seen so far.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Return the number of values seen.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Return the smallest value seen, or None if no values have been seen.
+    pub fn min(&self) -> Option<T> {
+        self.any().then_some(self.min)
+    }
+
+    /// Return the largest value seen, or None if no values have been seen.
+    pub fn max(&self) -> Option<T> {
+        self.any().then_some(self.max)
+    }
+
+    /// Return the mean of the values seen, or None if no values have been
+    /// seen.
+    pub fn mean(&self) -> Option<T> {
+        // Guard against rounding pushing the mean just out of range.
+        self.any().then(|| UnitValue::new(self.mean))
+    }
+
+    /// Return the population variance of the values seen, or None if no
+    /// values have been seen.
+    pub fn variance(&self) -> Option<f64> {
+        self.any().then(|| self.m2 / self.count as f64)
+    }
+
+    /// Return the population standard deviation of the values seen, or None
+    /// if no values have been seen.
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    fn any(&self) -> bool {
+        self.count > 0
+    }
+}
+
+impl<T: UnitValue> Default for Stats<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: UnitValue> Extend<T> for Stats<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(values: &[f64]) -> Vec<UnipolarFloat> {
+        values.iter().copied().map(UnipolarFloat::new).collect()
+    }
+
+    #[test]
+    fn percentile_interpolates_between_ranks() {
+        let values = levels(&[0.5, 0.0, 1.0, 0.25]);
+        let at = |q| percentile(&values, UnipolarFloat::new(q)).val();
+        assert_eq!(at(0.0), 0.0);
+        assert_eq!(at(0.25), 0.1875);
+        assert_eq!(at(1.0), 1.0);
+        assert_eq!(median(&values).val(), 0.375);
+        // The input is left in its original order.
+        assert_eq!(values, levels(&[0.5, 0.0, 1.0, 0.25]));
+    }
+
+    #[test]
+    fn percentile_of_an_odd_count_hits_a_value() {
+        let values = levels(&[0.75, 0.25, 0.5]);
+        assert_eq!(median(&values).val(), 0.5);
+    }
+
+    #[test]
+    fn percentile_of_repeated_values() {
+        let values = levels(&[0.5, 0.5, 0.5]);
+        assert_eq!(percentile(&values, UnipolarFloat::new(0.3)).val(), 0.5);
+    }
+
+    #[test]
+    fn percentile_of_few_values() {
+        assert_eq!(median(&[]), UnipolarFloat::ZERO);
+        let one = levels(&[0.25]);
+        assert_eq!(percentile(&one, UnipolarFloat::ZERO).val(), 0.25);
+        assert_eq!(percentile(&one, UnipolarFloat::ONE).val(), 0.25);
+    }
+}
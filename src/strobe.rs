@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use crate::{Phase, UnipolarFloat};
+
+/// Return true if a strobe with the provided duty cycle is lit at phase.
+/// The strobe is lit for the first duty fraction of each rotation; a duty
+/// of 0 is never lit and a duty of 1 is always lit.
+pub fn strobe(phase: Phase, duty: UnipolarFloat) -> bool {
+    phase.canonical() < duty.0
+}
+
+/// A free-running strobe, flashing at a configurable rate.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Strobe {
+    rate: f64,
+    duty: UnipolarFloat,
+    phase: Phase,
+}
+
+impl Strobe {
+    /// Create a strobe flashing rate times per second, lit for the duty
+    /// fraction of each flash. Negative rates are clamped to zero.
+    pub fn new(rate: f64, duty: UnipolarFloat) -> Self {
+        Self {
+            rate: f64::max(rate, 0.0),
+            duty,
+            phase: Phase::ZERO,
+        }
+    }
+
+    /// Return the number of flashes per second.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Set the number of flashes per second.
+    /// Negative rates are clamped to zero.
+    pub fn set_rate(&mut self, rate: f64) {
+        self.rate = f64::max(rate, 0.0);
+    }
+
+    pub fn duty(&self) -> UnipolarFloat {
+        self.duty
+    }
+
+    pub fn set_duty(&mut self, duty: UnipolarFloat) {
+        self.duty = duty;
+    }
+
+    /// Restart the current flash from the beginning.
+    pub fn reset(&mut self) {
+        self.phase = Phase::ZERO;
+    }
+
+    /// Return the current output level, either fully on or fully off.
+    pub fn value(&self) -> UnipolarFloat {
+        if strobe(self.phase, self.duty) {
+            UnipolarFloat::ONE
+        } else {
+            UnipolarFloat::ZERO
+        }
+    }
+
+    /// Advance the strobe by time dt, and return the new output level.
+    pub fn update(&mut self, dt: Duration) -> UnipolarFloat {
+        self.phase += self.rate * dt.as_secs_f64();
+        self.value()
+    }
+}
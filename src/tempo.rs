@@ -0,0 +1,128 @@
+use std::{
+    ops::{Div, Mul},
+    time::Duration,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Phase;
+
+/// A musical tempo in beats per minute.
+/// The tempo is never negative.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tempo(f64);
+
+impl Tempo {
+    /// Create a tempo from a value in beats per minute.
+    /// Values which are negative or not finite produce a tempo of zero.
+    pub fn new(bpm: f64) -> Self {
+        if bpm.is_finite() {
+            Self(f64::max(bpm, 0.0))
+        } else {
+            Self(0.0)
+        }
+    }
+
+    /// Create the tempo whose beats last for the provided duration.
+    /// A zero duration has no meaningful tempo, and produces a tempo of
+    /// zero.
+    pub fn from_beat_duration(beat: Duration) -> Self {
+        if beat.is_zero() {
+            return Self(0.0);
+        }
+        Self::new(60.0 / beat.as_secs_f64())
+    }
+
+    /// Return the tempo in beats per minute.
+    pub fn bpm(&self) -> f64 {
+        self.0
+    }
+
+    /// Return the tempo in beats per second.
+    pub fn hz(&self) -> f64 {
+        self.0 / 60.0
+    }
+
+    /// Return the number of beats that elapse over the provided duration.
+    pub fn beats_in(&self, elapsed: Duration) -> f64 {
+        elapsed.as_secs_f64() * self.hz()
+    }
+
+    /// Return the phase within the current beat after the provided time has
+    /// elapsed since beat zero.
+    /// At a tempo of zero, the phase stays at zero.
+    pub fn phase_at(&self, elapsed: Duration) -> Phase {
+        // Take the whole seconds and the fraction separately, so that long
+        // elapsed times do not lose the precision of the fraction.
+        let hz = self.hz();
+        let whole = (elapsed.as_secs() as f64 * hz).fract();
+        Phase::new(whole + elapsed.subsec_nanos() as f64 * 1e-9 * hz)
+    }
+
+    /// Return the length of time taken by the provided number of beats.
+    /// Negative beat counts take no time. At a tempo of zero, any positive
+    /// number of beats saturates at Duration::MAX.
+    pub fn beats_to_duration(&self, beats: f64) -> Duration {
+        if beats <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::try_from_secs_f64(beats / self.hz()).unwrap_or(Duration::MAX)
+    }
+
+    /// Return the length of one beat.
+    pub fn beat_duration(&self) -> Duration {
+        self.beats_to_duration(1.0)
+    }
+}
+
+impl Mul<f64> for Tempo {
+    type Output = Self;
+    /// Scale the tempo, such as doubling for eighth notes.
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.0 * rhs)
+    }
+}
+
+impl Div<f64> for Tempo {
+    type Output = Self;
+    /// Divide the tempo, such as by 4 to get the rate of bars in 4/4.
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.0 / rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_finite_tempos_are_zero() {
+        for tempo in [
+            Tempo::new(f64::INFINITY),
+            Tempo::new(f64::NAN),
+            Tempo::from_beat_duration(Duration::ZERO),
+        ] {
+            assert_eq!(tempo.bpm(), 0.0);
+            assert_eq!(tempo.phase_at(Duration::from_secs(1)).val(), 0.0);
+        }
+    }
+
+    #[test]
+    fn phase_at_long_elapsed_time() {
+        let tempo = Tempo::new(120.0);
+        let elapsed = Duration::from_secs(10 * 24 * 3600) + Duration::from_millis(125);
+        assert_eq!(tempo.phase_at(elapsed).val(), 0.25);
+    }
+
+    #[test]
+    fn phase_at_inexact_beat_duration() {
+        // A beat at 140 BPM is not a whole number of nanoseconds.
+        let tempo = Tempo::new(140.0);
+        let elapsed = Duration::from_secs(10 * 24 * 3600) + Duration::from_millis(123);
+        let phase = tempo.phase_at(elapsed).val();
+        assert!((phase - 0.287).abs() < 1e-9);
+        assert!((phase - tempo.beats_in(elapsed).fract()).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use crate::{filter::smoothing_alpha, Phase};
+
+/// Unwraps a stream of phase samples into a continuous count of turns, such
+/// as for counting the bars elapsed on a master clock.
+///
+/// Each sample is assumed to have moved the shortest way around the circle
+/// from the previous one, so samples must be taken at least twice per
+/// rotation. Jitter backwards across the wrap point briefly reduces the turn
+/// count rather than adding a spurious rotation.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PhaseTracker {
+    last: Option<Phase>,
+    /// The net number of times the phase has wrapped around.
+    wraps: i64,
+    cycles: i64,
+}
+
+impl PhaseTracker {
+    /// Create a tracker which has not yet seen a sample.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the total unwrapped phase, in turns. The fractional part is the
+    /// most recent phase sample.
+    pub fn turns(&self) -> f64 {
+        match self.last {
+            Some(last) => self.wraps as f64 + last.canonical(),
+            None => 0.0,
+        }
+    }
+
+    /// Return the number of completed cycles.
+    /// This only ever increases, so a cycle that is un-done by jitter near
+    /// the wrap point is not counted a second time.
+    pub fn cycles(&self) -> i64 {
+        self.cycles
+    }
+
+    /// Forget all history, returning to the initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Incorporate a new phase sample, returning the number of cycles it
+    /// completed.
+    pub fn update(&mut self, phase: Phase) -> u64 {
+        if let Some(last) = self.last {
+            // Count wraps exactly, rather than accumulating deltas, so that
+            // rounding error cannot delay or lose a wrap.
+            let delta = last.distance_to(phase).val();
+            let (from, to) = (last.canonical(), phase.canonical());
+            if delta > 0.0 && to < from {
+                self.wraps += 1;
+            } else if delta < 0.0 && to > from {
+                self.wraps -= 1;
+            }
+        }
+        self.last = Some(phase);
+
+        if self.wraps > self.cycles {
+            let new = self.wraps - self.cycles;
+            self.cycles = self.wraps;
+            new as u64
+        } else {
+            0
+        }
+    }
+}
+
+/// Estimates the rate of rotation of an external phase, such as a clock
+/// synced from another machine, from periodic samples.
+///
+/// As with PhaseTracker, samples must be taken at least twice per rotation
+/// for the estimate to be correct; the estimate is smoothed with time
+/// constant tau to reject jitter in sample timing.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FrequencyEstimator {
+    tau: Duration,
+    last: Option<Phase>,
+    /// Time elapsed since the last sample was measured against.
+    elapsed: Duration,
+    rate: Option<f64>,
+}
+
+impl FrequencyEstimator {
+    pub fn new(tau: Duration) -> Self {
+        Self {
+            tau,
+            last: None,
+            elapsed: Duration::ZERO,
+            rate: None,
+        }
+    }
+
+    /// Return the estimated rate in rotations per second, negative if the
+    /// phase is running backwards.
+    /// Return None until at least two samples have been seen.
+    pub fn rate(&self) -> Option<f64> {
+        self.rate
+    }
+
+    /// Forget all history, returning to the initial state.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.tau);
+    }
+
+    /// Incorporate a phase sample taken time dt after the previous one, and
+    /// return the new estimate.
+    /// Samples taken at the same time as the previous one are ignored.
+    pub fn update(&mut self, phase: Phase, dt: Duration) -> Option<f64> {
+        let Some(last) = self.last else {
+            self.last = Some(phase);
+            return self.rate;
+        };
+        self.elapsed += dt;
+        if self.elapsed.is_zero() {
+            return self.rate;
+        }
+        let measured = last.distance_to(phase).val() / self.elapsed.as_secs_f64();
+        self.rate = Some(match self.rate {
+            None => measured,
+            Some(rate) => rate + (measured - rate) * smoothing_alpha(self.elapsed, self.tau),
+        });
+        self.last = Some(phase);
+        self.elapsed = Duration::ZERO;
+        self.rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_counts_many_rotations_exactly() {
+        let mut tracker = PhaseTracker::new();
+        let mut total = 0;
+        for i in 0..100_000u64 {
+            total += tracker.update(Phase::new(i as f64 * 0.1));
+            if i % 10 == 0 {
+                assert_eq!(tracker.cycles(), (i / 10) as i64, "sample {}", i);
+            }
+        }
+        assert_eq!(total, 9999);
+        assert_eq!(tracker.cycles(), 9999);
+        let turns = tracker.turns();
+        assert_eq!(turns.floor(), 9999.0);
+        assert!((turns.fract() - 0.9).abs() < 1e-9, "{}", turns);
+    }
+
+    #[test]
+    fn tracker_ignores_jitter_across_wrap() {
+        let mut tracker = PhaseTracker::new();
+        let mut total = 0;
+        for v in [0.9, 0.99, 0.01, 0.98, 0.02, 0.03] {
+            total += tracker.update(Phase::new(v));
+        }
+        assert_eq!(total, 1);
+        assert_eq!(tracker.cycles(), 1);
+        assert!((tracker.turns() - 1.03).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::UnipolarFloat;
+
+/// A transition of a Trigger's input across its threshold.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Edge {
+    /// The input rose to or above the threshold.
+    Rising,
+    /// The input fell back below the threshold.
+    Falling,
+}
+
+/// An edge detector, turning a continuous control value into discrete
+/// events when it crosses a threshold.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Trigger {
+    threshold: UnipolarFloat,
+    hysteresis: UnipolarFloat,
+    high: bool,
+}
+
+impl Trigger {
+    /// Create a trigger with the provided threshold, starting low.
+    pub fn new(threshold: UnipolarFloat) -> Self {
+        Self {
+            threshold,
+            hysteresis: UnipolarFloat::ZERO,
+            high: false,
+        }
+    }
+
+    /// Require the input to fall this far below the threshold before
+    /// reporting a falling edge, so that noise near the threshold does not
+    /// produce a burst of events.
+    pub fn with_hysteresis(mut self, hysteresis: UnipolarFloat) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    pub fn threshold(&self) -> UnipolarFloat {
+        self.threshold
+    }
+
+    pub fn set_threshold(&mut self, threshold: UnipolarFloat) {
+        self.threshold = threshold;
+    }
+
+    /// Return true if the input is currently above the threshold.
+    pub fn is_high(&self) -> bool {
+        self.high
+    }
+
+    /// Return to the low state without emitting an event.
+    pub fn reset(&mut self) {
+        self.high = false;
+    }
+
+    /// Process a new input value, returning the edge it produced, if any.
+    pub fn update(&mut self, value: UnipolarFloat) -> Option<Edge> {
+        if !self.high && value >= self.threshold {
+            self.high = true;
+            Some(Edge::Rising)
+        } else if self.high && value.0 < self.threshold.0 - self.hysteresis.0 {
+            self.high = false;
+            Some(Edge::Falling)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Interpolate, UnipolarFloat};
+
+/// The shape of a transition over time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Easing {
+    /// Constant rate of change.
+    #[default]
+    Linear,
+    /// Start slowly and accelerate.
+    EaseIn,
+    /// Start quickly and decelerate.
+    EaseOut,
+    /// Start and end slowly.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map linear progress through a transition onto eased progress.
+    pub fn apply(&self, t: UnipolarFloat) -> UnipolarFloat {
+        let t = t.val();
+        UnipolarFloat::new(match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => t * (2.0 - t),
+            Self::EaseInOut => t * t * (3.0 - 2.0 * t),
+        })
+    }
+}
+
+/// Moves a value from a start point to a target over a fixed duration.
+/// The target can be changed mid-flight, in which case the tween restarts
+/// from its current value.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Tween<T> {
+    start: T,
+    target: T,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+impl<T: Interpolate> Tween<T> {
+    /// Create a tween at rest at the provided value.
+    pub fn new(value: T, duration: Duration, easing: Easing) -> Self {
+        Self {
+            start: value,
+            target: value,
+            duration,
+            elapsed: duration,
+            easing,
+        }
+    }
+
+    /// Return how far through the transition this tween is.
+    pub fn progress(&self) -> UnipolarFloat {
+        if self.duration.is_zero() {
+            return UnipolarFloat::ONE;
+        }
+        UnipolarFloat::new(self.elapsed.as_secs_f64() / self.duration.as_secs_f64())
+    }
+
+    /// Return the current value.
+    pub fn value(&self) -> T {
+        self.start
+            .interp(self.target, self.easing.apply(self.progress()))
+    }
+
+    /// Return the value this tween is moving towards.
+    pub fn target(&self) -> T {
+        self.target
+    }
+
+    /// Return true if the tween has reached its target.
+    pub fn is_done(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Start moving from the current value towards a new target.
+    pub fn retarget(&mut self, target: T) {
+        self.start = self.value();
+        self.target = target;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Jump immediately to the provided value and stop.
+    pub fn set(&mut self, value: T) {
+        self.start = value;
+        self.target = value;
+        self.elapsed = self.duration;
+    }
+
+    /// Change the duration of subsequent transitions.
+    /// A transition in progress will also use the new duration.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Change the easing curve.
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
+    /// Advance the tween by dt and return the new value.
+    pub fn update(&mut self, dt: Duration) -> T {
+        self.elapsed = Duration::min(self.elapsed.saturating_add(dt), self.duration);
+        self.value()
+    }
+}
@@ -0,0 +1,71 @@
+use std::ops::RangeInclusive;
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+/// The operations common to every range-constrained numeric type, for use
+/// in generic code.
+pub trait UnitValue: Copy + PartialOrd + Into<f64> {
+    /// The lowest value in the range.
+    const MIN: Self;
+    /// The highest value in the range.
+    const MAX: Self;
+    const ZERO: Self;
+    /// True if out-of-range values wrap around, false if they are clamped.
+    const WRAPS: bool;
+
+    /// Bring the provided value into range, by clamping or wrapping.
+    fn new(v: f64) -> Self;
+
+    /// Return the inner float value.
+    fn val(&self) -> f64;
+
+    /// Return the range of the inner float value.
+    fn range() -> RangeInclusive<f64> {
+        Self::MIN.val()..=Self::MAX.val()
+    }
+}
+
+impl UnitValue for UnipolarFloat {
+    const MIN: Self = Self(0.0);
+    const MAX: Self = Self(1.0);
+    const ZERO: Self = Self(0.0);
+    const WRAPS: bool = false;
+
+    fn new(v: f64) -> Self {
+        Self::new(v)
+    }
+
+    fn val(&self) -> f64 {
+        self.0
+    }
+}
+
+impl UnitValue for BipolarFloat {
+    const MIN: Self = Self(-1.0);
+    const MAX: Self = Self(1.0);
+    const ZERO: Self = Self(0.0);
+    const WRAPS: bool = false;
+
+    fn new(v: f64) -> Self {
+        Self::new(v)
+    }
+
+    fn val(&self) -> f64 {
+        self.0
+    }
+}
+
+impl UnitValue for Phase {
+    const MIN: Self = Self(0.0);
+    const MAX: Self = Self(1.0);
+    const ZERO: Self = Self(0.0);
+    const WRAPS: bool = true;
+
+    fn new(v: f64) -> Self {
+        Self::new(v)
+    }
+
+    fn val(&self) -> f64 {
+        self.0
+    }
+}
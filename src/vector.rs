@@ -0,0 +1,194 @@
+use std::f64::consts::TAU;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BipolarFloat, Interpolate, Phase, UnipolarFloat};
+
+/// A pair of coupled bipolar values, such as the position of a joystick
+/// controlling pan and tilt.
+/// The type upholds the invariant that the vector lies within the unit disk
+/// by scaling down vectors which are too long, preserving their direction.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "RawBipolar2"))]
+pub struct Bipolar2 {
+    x: BipolarFloat,
+    y: BipolarFloat,
+}
+
+impl Bipolar2 {
+    pub const ZERO: Self = Self {
+        x: BipolarFloat::ZERO,
+        y: BipolarFloat::ZERO,
+    };
+
+    /// Create a vector from the provided components, scaling it down onto
+    /// the unit circle if it lies outside of it.
+    /// Infinite components point along their axis and NaN components are
+    /// treated as zero.
+    pub fn new(x: f64, y: f64) -> Self {
+        let (x, y) = if x.is_infinite() || y.is_infinite() {
+            // Any finite component is negligible next to an infinite one.
+            let axis = |v: f64| if v.is_infinite() { v.signum() } else { 0.0 };
+            (axis(x), axis(y))
+        } else {
+            let finite = |v: f64| if v.is_nan() { 0.0 } else { v };
+            (finite(x), finite(y))
+        };
+        let magnitude = x.hypot(y);
+        let (x, y) = if magnitude > 1.0 {
+            (x / magnitude, y / magnitude)
+        } else {
+            (x, y)
+        };
+        Self {
+            x: BipolarFloat::new(x),
+            y: BipolarFloat::new(y),
+        }
+    }
+
+    /// Create a vector from a pair of bipolar values, scaling it down onto
+    /// the unit circle if it lies outside of it.
+    pub fn from_components(x: BipolarFloat, y: BipolarFloat) -> Self {
+        Self::new(x.0, y.0)
+    }
+
+    pub fn x(&self) -> BipolarFloat {
+        self.x
+    }
+
+    pub fn y(&self) -> BipolarFloat {
+        self.y
+    }
+
+    /// Return the length of this vector.
+    pub fn magnitude(&self) -> UnipolarFloat {
+        UnipolarFloat::new(self.x.0.hypot(self.y.0))
+    }
+
+    /// Rotate this vector counterclockwise by the provided fraction of a
+    /// rotation.
+    pub fn rotate(&self, angle: Phase) -> Self {
+        let (sin, cos) = (angle.0 * TAU).sin_cos();
+        let (x, y) = (self.x.0, self.y.0);
+        Self::new(x * cos - y * sin, x * sin + y * cos)
+    }
+
+    /// Apply a radial deadzone of the provided radius, such as to ignore
+    /// the resting noise of a joystick.
+    /// Vectors inside of the deadzone become zero; the rest are rescaled so
+    /// that the output still spans the whole disk, without a jump at the
+    /// edge of the deadzone.
+    pub fn deadzone(&self, radius: UnipolarFloat) -> Self {
+        let magnitude = self.magnitude().0;
+        if magnitude <= radius.0 {
+            return Self::ZERO;
+        }
+        let scale = (magnitude - radius.0) / (1.0 - radius.0) / magnitude;
+        Self::new(self.x.0 * scale, self.y.0 * scale)
+    }
+
+    /// Return this vector in polar form.
+    /// The zero vector has an angle of zero.
+    pub fn to_polar(&self) -> Polar {
+        Polar {
+            angle: Phase::new(self.y.0.atan2(self.x.0) / TAU),
+            radius: self.magnitude(),
+        }
+    }
+}
+
+/// The serialized form, scaled back into the unit disk when deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawBipolar2 {
+    x: f64,
+    y: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<RawBipolar2> for Bipolar2 {
+    fn from(raw: RawBipolar2) -> Self {
+        Self::new(raw.x, raw.y)
+    }
+}
+
+/// A point within the unit disk in polar form.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Polar {
+    /// The angle counterclockwise from the positive x axis, as a fraction of
+    /// a rotation.
+    pub angle: Phase,
+    /// The distance from the center.
+    pub radius: UnipolarFloat,
+}
+
+impl Polar {
+    pub fn new(angle: Phase, radius: UnipolarFloat) -> Self {
+        Self { angle, radius }
+    }
+
+    /// Return this point in cartesian form.
+    pub fn to_cartesian(&self) -> Bipolar2 {
+        let (sin, cos) = (self.angle.0 * TAU).sin_cos();
+        Bipolar2::new(self.radius.0 * cos, self.radius.0 * sin)
+    }
+}
+
+impl From<Bipolar2> for Polar {
+    fn from(value: Bipolar2) -> Self {
+        value.to_polar()
+    }
+}
+
+impl From<Polar> for Bipolar2 {
+    fn from(value: Polar) -> Self {
+        value.to_cartesian()
+    }
+}
+
+impl Interpolate for Polar {
+    /// Move the angle around the circle in whichever direction is shortest,
+    /// and the radius linearly.
+    fn interp(self, other: Self, t: UnipolarFloat) -> Self {
+        Self {
+            angle: self.angle.interp(other.angle, t),
+            radius: self.radius.interp(other.radius, t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infinite_components_point_along_their_axis() {
+        let v = Bipolar2::new(f64::INFINITY, 0.0);
+        assert_eq!((v.x().val(), v.y().val()), (1.0, 0.0));
+        let v = Bipolar2::new(f64::NEG_INFINITY, 0.5);
+        assert_eq!((v.x().val(), v.y().val()), (-1.0, 0.0));
+        let v = Bipolar2::new(f64::INFINITY, f64::INFINITY);
+        assert!((v.magnitude().val() - 1.0).abs() < 1e-12);
+        assert_eq!(v.x(), v.y());
+        assert!(v.x().val() > 0.0);
+    }
+
+    #[test]
+    fn nan_components_are_zero() {
+        let v = Bipolar2::new(f64::NAN, 0.5);
+        assert_eq!((v.x().val(), v.y().val()), (0.0, 0.5));
+        assert_eq!(Bipolar2::new(f64::NAN, f64::NAN), Bipolar2::ZERO);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_scales_into_disk() {
+        let bytes = bincode::serialize(&(1.0f64, 1.0f64)).unwrap();
+        let v: Bipolar2 = bincode::deserialize(&bytes).unwrap();
+        assert!((v.magnitude().val() - 1.0).abs() < 1e-12);
+        assert_eq!(v.x(), v.y());
+    }
+}
@@ -0,0 +1,50 @@
+//! JavaScript bindings, so web UIs construct values with the same range
+//! handling as Rust code.
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+#[wasm_bindgen]
+impl UnipolarFloat {
+    /// Clamp the provided value to the unit range.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(v: f64) -> Self {
+        Self::new(v)
+    }
+
+    /// The inner float value.
+    #[wasm_bindgen(getter, js_name = value)]
+    pub fn js_value(&self) -> f64 {
+        self.0
+    }
+}
+
+#[wasm_bindgen]
+impl BipolarFloat {
+    /// Clamp the provided value to the bipolar unit range.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(v: f64) -> Self {
+        Self::new(v)
+    }
+
+    /// The inner float value.
+    #[wasm_bindgen(getter, js_name = value)]
+    pub fn js_value(&self) -> f64 {
+        self.0
+    }
+}
+
+#[wasm_bindgen]
+impl Phase {
+    /// Wrap the provided value into the unit range.
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(v: f64) -> Self {
+        Self::new(v)
+    }
+
+    /// The inner phase.
+    #[wasm_bindgen(getter, js_name = value)]
+    pub fn js_value(&self) -> f64 {
+        self.0
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{BipolarFloat, Interpolate, Phase, UnipolarFloat};
+
+/// Read a single-cycle waveform from table at the provided phase, linearly
+/// interpolating between samples.
+/// The table is treated as circular, so phases past the last sample
+/// interpolate back towards the first. An empty table reads as zero.
+pub fn wavetable_read(table: &[BipolarFloat], phase: Phase) -> BipolarFloat {
+    let len = table.len();
+    if len == 0 {
+        return BipolarFloat::ZERO;
+    }
+    let i = phase.sector(len);
+    let t = UnipolarFloat(phase.sector_phase(len).0);
+    table[i].interp(table[(i + 1) % len], t)
+}
@@ -0,0 +1,67 @@
+//! egui widgets for editing values in place.
+//!
+//! Sliders and drag values respect the range of each type; phases wrap
+//! around when dragged past either end.
+use egui::{DragValue, Response, Slider, Ui};
+
+use crate::{BipolarFloat, Phase, UnipolarFloat};
+
+/// How far a drag value moves per point of pointer motion.
+const DRAG_SPEED: f64 = 0.005;
+
+/// Edit a value through a copy of its inner float, then restore the range
+/// invariant on the way back in.
+fn edit<T, F>(value: &mut T, v: f64, new: fn(f64) -> T, widget: F) -> Response
+where
+    F: FnOnce(&mut f64) -> Response,
+{
+    let mut v = v;
+    let response = widget(&mut v);
+    if response.changed() {
+        *value = new(v);
+    }
+    response
+}
+
+/// Show a slider editing a UnipolarFloat.
+pub fn unipolar_slider(ui: &mut Ui, value: &mut UnipolarFloat) -> Response {
+    edit(value, value.0, UnipolarFloat::new, |v| {
+        ui.add(Slider::new(v, 0.0..=1.0))
+    })
+}
+
+/// Show a drag value editing a UnipolarFloat.
+pub fn unipolar_drag(ui: &mut Ui, value: &mut UnipolarFloat) -> Response {
+    edit(value, value.0, UnipolarFloat::new, |v| {
+        ui.add(DragValue::new(v).speed(DRAG_SPEED).range(0.0..=1.0))
+    })
+}
+
+/// Show a slider editing a BipolarFloat.
+pub fn bipolar_slider(ui: &mut Ui, value: &mut BipolarFloat) -> Response {
+    edit(value, value.0, BipolarFloat::new, |v| {
+        ui.add(Slider::new(v, -1.0..=1.0))
+    })
+}
+
+/// Show a drag value editing a BipolarFloat.
+pub fn bipolar_drag(ui: &mut Ui, value: &mut BipolarFloat) -> Response {
+    edit(value, value.0, BipolarFloat::new, |v| {
+        ui.add(DragValue::new(v).speed(DRAG_SPEED).range(-1.0..=1.0))
+    })
+}
+
+/// Show a slider editing a Phase.
+/// The slider spans a single rotation, so it cannot wrap.
+pub fn phase_slider(ui: &mut Ui, value: &mut Phase) -> Response {
+    edit(value, value.0, Phase::clamped, |v| {
+        ui.add(Slider::new(v, 0.0..=1.0))
+    })
+}
+
+/// Show a drag value editing a Phase, wrapping around past either end.
+pub fn phase_drag(ui: &mut Ui, value: &mut Phase) -> Response {
+    edit(value, value.0, Phase::new, |v| {
+        ui.add(DragValue::new(v).speed(DRAG_SPEED))
+    })
+}